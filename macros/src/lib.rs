@@ -0,0 +1,185 @@
+// Copyright 2013-2014 The Rust Project Developers.
+// Copyright 2018 The Uuid Project Developers.
+//
+// See the COPYRIGHT file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Implementation details of the `uuid` crate's procedural macros.
+//!
+//! This crate is an implementation detail and shouldn't be used directly.
+//! Enable the `macros` feature of `uuid` and use `uuid::uuid!` and
+//! `uuid::new_v4_const!` instead.
+
+extern crate proc_macro;
+
+use proc_macro::{
+    Delimiter, Group, Ident, Literal, Punct, Spacing, Span, TokenStream,
+    TokenTree,
+};
+use std::iter::FromIterator;
+
+/// Parses a UUID string literal at compile time.
+///
+/// See the documentation of `uuid::uuid!`.
+#[proc_macro]
+pub fn uuid(input: TokenStream) -> TokenStream {
+    let mut tokens = input.into_iter();
+
+    let literal = match (tokens.next(), tokens.next()) {
+        (Some(TokenTree::Literal(literal)), None) => literal,
+        (Some(TokenTree::Group(group)), None)
+            if group.delimiter() == Delimiter::None =>
+        {
+            // Literals passed through `macro_rules!` arrive wrapped in an
+            // invisible group.
+            return uuid(group.stream());
+        }
+        (Some(token), _) => {
+            return error(token.span(), "expected a single string literal")
+        }
+        (None, _) => {
+            return error(Span::call_site(), "expected a string literal")
+        }
+    };
+
+    if !is_string_literal(&literal) {
+        return error(literal.span(), "expected a string literal");
+    }
+
+    parse_str_const(literal)
+}
+
+/// Generates a random (V4) UUID at compile time.
+///
+/// See the documentation of `uuid::new_v4_const!`.
+#[proc_macro]
+pub fn new_v4_const(input: TokenStream) -> TokenStream {
+    if let Some(token) = input.into_iter().next() {
+        return error(token.span(), "expected no arguments");
+    }
+
+    let mut bytes = [0u8; 16];
+    if let Err(err) = getrandom::getrandom(&mut bytes) {
+        return error(
+            Span::call_site(),
+            &format!("could not retrieve random bytes for uuid: {}", err),
+        );
+    }
+
+    bytes[6] = (bytes[6] & 0x0f) | 0x40;
+    bytes[8] = (bytes[8] & 0x3f) | 0x80;
+
+    from_bytes(bytes, Span::call_site())
+}
+
+/// Whether `literal` is a string literal, raw or not.
+fn is_string_literal(literal: &Literal) -> bool {
+    let repr = literal.to_string();
+
+    repr.starts_with('"') || repr.starts_with("r\"") || repr.starts_with("r#")
+}
+
+/// Expands to `{ const UUID: ::uuid::Uuid =
+/// ::uuid::Uuid::parse_str_const("..."); UUID }`.
+///
+/// Evaluating the literal in a `const` item means an invalid one fails to
+/// compile wherever the macro is used, with the message
+/// `uuid::Uuid::parse_str` would return.
+fn parse_str_const(literal: Literal) -> TokenStream {
+    let span = literal.span();
+
+    let mut tokens = vec![
+        TokenTree::Ident(Ident::new("const", span)),
+        TokenTree::Ident(Ident::new("UUID", span)),
+        punct(':', span),
+    ];
+    tokens.extend(path(&["uuid", "Uuid"], span));
+    tokens.push(punct('=', span));
+    tokens.extend(path(&["uuid", "Uuid", "parse_str_const"], span));
+    tokens.push(TokenTree::Group(Group::new(
+        Delimiter::Parenthesis,
+        TokenStream::from_iter(vec![TokenTree::Literal(literal)]),
+    )));
+    tokens.push(punct(';', span));
+    tokens.push(TokenTree::Ident(Ident::new("UUID", span)));
+
+    let mut block =
+        Group::new(Delimiter::Brace, TokenStream::from_iter(tokens));
+    block.set_span(span);
+
+    TokenStream::from_iter(vec![TokenTree::Group(block)])
+}
+
+/// Expands to `::uuid::Uuid::from_bytes([...])`.
+fn from_bytes(bytes: [u8; 16], span: Span) -> TokenStream {
+    let mut elements = Vec::with_capacity(32);
+    for byte in bytes.iter() {
+        let mut literal = Literal::u8_suffixed(*byte);
+        literal.set_span(span);
+
+        elements.push(TokenTree::Literal(literal));
+        elements.push(TokenTree::Punct(Punct::new(',', Spacing::Alone)));
+    }
+
+    let array =
+        Group::new(Delimiter::Bracket, TokenStream::from_iter(elements));
+
+    let mut tokens = path(&["uuid", "Uuid", "from_bytes"], span);
+    tokens.push(TokenTree::Group(Group::new(
+        Delimiter::Parenthesis,
+        TokenStream::from_iter(vec![TokenTree::Group(array)]),
+    )));
+
+    TokenStream::from_iter(tokens)
+}
+
+/// Expands to `::core::compile_error!("message")` at the given span.
+fn error(span: Span, message: &str) -> TokenStream {
+    let mut tokens = path(&["core", "compile_error"], span);
+
+    let mut bang = Punct::new('!', Spacing::Alone);
+    bang.set_span(span);
+    tokens.push(TokenTree::Punct(bang));
+
+    let mut literal = Literal::string(message);
+    literal.set_span(span);
+
+    let mut group = Group::new(
+        Delimiter::Brace,
+        TokenStream::from_iter(vec![TokenTree::Literal(literal)]),
+    );
+    group.set_span(span);
+    tokens.push(TokenTree::Group(group));
+
+    TokenStream::from_iter(tokens)
+}
+
+/// Builds a single punctuation token at the given span.
+fn punct(ch: char, span: Span) -> TokenTree {
+    let mut punct = Punct::new(ch, Spacing::Alone);
+    punct.set_span(span);
+
+    TokenTree::Punct(punct)
+}
+
+/// Builds an absolute path, like `::a::b`.
+fn path(segments: &[&str], span: Span) -> Vec<TokenTree> {
+    let mut tokens = Vec::new();
+    for segment in segments {
+        let mut first = Punct::new(':', Spacing::Joint);
+        first.set_span(span);
+        let mut second = Punct::new(':', Spacing::Alone);
+        second.set_span(span);
+
+        tokens.push(TokenTree::Punct(first));
+        tokens.push(TokenTree::Punct(second));
+        tokens.push(TokenTree::Ident(Ident::new(segment, span)));
+    }
+
+    tokens
+}
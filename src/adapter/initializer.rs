@@ -0,0 +1,769 @@
+// Copyright 2013-2014 The Rust Project Developers.
+// Copyright 2018 The Uuid Project Developers.
+//
+// See the COPYRIGHT file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! The C `GUID` initializer and `DEFINE_GUID` formats.
+//!
+//! A `GUID` is split into the fields of a [`Uuid`] given by
+//! [`Uuid::as_fields`], with each byte of the last field written on its
+//! own, like `{0x6b29fc40, 0xca47, 0x1067, {0xb3, 0x1d, 0x00, 0xdd, 0x01,
+//! 0x06, 0x62, 0x0b}}`.
+//!
+//! [`Uuid`]: ../struct.Uuid.html
+//! [`Uuid::as_fields`]: ../struct.Uuid.html#method.as_fields
+
+use super::{Initializer, InitializerRef, LOWER, UPPER};
+use crate::parser::{error, Error};
+use crate::std::{convert::TryFrom, fmt, str, str::FromStr};
+use crate::Uuid;
+
+/// The length of the fields without the braces around them, as written in
+/// `DEFINE_GUID`.
+const FLAT_LENGTH: usize = 74;
+
+/// Writes the fields of `uuid` as hex literals separated by commas to
+/// `buffer`, with the braces of a struct initializer if `braces` is set.
+fn encode<'a>(
+    buffer: &'a mut [u8],
+    uuid: &Uuid,
+    upper: bool,
+    braces: bool,
+) -> &'a mut str {
+    let len = if braces {
+        Initializer::LENGTH
+    } else {
+        FLAT_LENGTH
+    };
+
+    let mut writer = Writer {
+        buffer: &mut buffer[..len],
+        len: 0,
+        hex: if upper { &UPPER } else { &LOWER },
+    };
+
+    let (d1, d2, d3, d4) = uuid.as_fields();
+
+    if braces {
+        writer.push(b"{");
+    }
+    writer.field(d1, 8);
+    writer.push(b", ");
+    writer.field(d2.into(), 4);
+    writer.push(b", ");
+    writer.field(d3.into(), 4);
+    writer.push(if braces { b", {" } else { b", " });
+    for (i, &byte) in d4.iter().enumerate() {
+        if i > 0 {
+            writer.push(b", ");
+        }
+        writer.field(byte.into(), 2);
+    }
+    if braces {
+        writer.push(b"}}");
+    }
+
+    str::from_utf8_mut(&mut buffer[..len])
+        .expect("found non-ASCII output characters while encoding a UUID")
+}
+
+struct Writer<'a> {
+    buffer: &'a mut [u8],
+    len: usize,
+    hex: &'static [u8; 16],
+}
+
+impl<'a> Writer<'a> {
+    fn push(&mut self, bytes: &[u8]) {
+        self.buffer[self.len..self.len + bytes.len()].copy_from_slice(bytes);
+        self.len += bytes.len();
+    }
+
+    /// Writes `value` as a `0x` literal of exactly `digits` hex digits.
+    fn field(&mut self, value: u32, digits: usize) {
+        self.push(b"0x");
+        for shift in (0..digits).rev() {
+            self.buffer[self.len] =
+                self.hex[(value >> (shift * 4)) as usize & 0xf];
+            self.len += 1;
+        }
+    }
+}
+
+/// Parses the fields of a `GUID` from the tokens of some C source.
+struct Parser<'a> {
+    input: &'a str,
+    index: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn new(input: &'a str) -> Self {
+        Parser { input, index: 0 }
+    }
+
+    fn rest(&self) -> &'a str {
+        &self.input[self.index..]
+    }
+
+    fn skip_whitespace(&mut self) {
+        let rest = self.rest();
+        self.index += rest.len() - rest.trim_start().len();
+    }
+
+    /// The error for finding something other than `expected` at the
+    /// current position.
+    fn unexpected(&self, expected: &'static str) -> Error {
+        match self.rest().chars().next() {
+            Some(found) => Error::InvalidCharacter {
+                expected,
+                found,
+                index: self.index,
+                start: 0,
+                urn: error::UrnPrefix::NotApplicable,
+            },
+            None => Error::UnexpectedEnd {
+                expected,
+                index: self.index,
+            },
+        }
+    }
+
+    fn token(&mut self, token: &'static str) -> Result<(), Error> {
+        self.skip_whitespace();
+
+        if self.rest().starts_with(token) {
+            self.index += token.len();
+            Ok(())
+        } else {
+            Err(self.unexpected(token))
+        }
+    }
+
+    fn end(&mut self) -> Result<(), Error> {
+        self.skip_whitespace();
+
+        if self.rest().is_empty() {
+            Ok(())
+        } else {
+            Err(self.unexpected("the end of the input"))
+        }
+    }
+
+    /// Parses a C identifier, such as the name given to `DEFINE_GUID`.
+    fn identifier(&mut self) -> Result<&'a str, Error> {
+        self.skip_whitespace();
+
+        let rest = self.rest();
+        let len = rest
+            .bytes()
+            .take_while(|b| b.is_ascii_alphanumeric() || *b == b'_')
+            .count();
+
+        if len == 0 || rest.as_bytes()[0].is_ascii_digit() {
+            return Err(self.unexpected("a C identifier"));
+        }
+
+        self.index += len;
+        Ok(&rest[..len])
+    }
+
+    /// Parses a hex literal of up to `digits` digits, such as `0x1067`,
+    /// with any integer suffix like `L`.
+    fn field(&mut self, digits: usize) -> Result<u32, Error> {
+        self.skip_whitespace();
+
+        if !self.rest().starts_with("0x") && !self.rest().starts_with("0X") {
+            return Err(self.unexpected("0x"));
+        }
+        self.index += 2;
+
+        let rest = self.rest();
+        let len = rest.bytes().take_while(u8::is_ascii_hexdigit).count();
+
+        if len == 0 {
+            return Err(self.unexpected("0123456789abcdefABCDEF"));
+        }
+        if len > digits {
+            self.index += digits;
+            return Err(self.unexpected(match digits {
+                8 => "at most 8 hex digits",
+                4 => "at most 4 hex digits",
+                _ => "at most 2 hex digits",
+            }));
+        }
+
+        let value = u32::from_str_radix(&rest[..len], 16)
+            .expect("found an invalid hex literal while parsing a GUID");
+
+        self.index += len;
+        self.index += self
+            .rest()
+            .bytes()
+            .take_while(|b| matches!(b, b'u' | b'U' | b'l' | b'L'))
+            .count();
+
+        Ok(value)
+    }
+
+    /// Parses the fields of a `GUID`, with the last field in braces if
+    /// `braces` is set.
+    fn fields(&mut self, braces: bool) -> Result<Uuid, Error> {
+        let d1 = self.field(8)?;
+        self.token(",")?;
+        let d2 = self.field(4)? as u16;
+        self.token(",")?;
+        let d3 = self.field(4)? as u16;
+        self.token(",")?;
+
+        if braces {
+            self.token("{")?;
+        }
+
+        let mut d4 = [0u8; 8];
+        for (i, byte) in d4.iter_mut().enumerate() {
+            if i > 0 {
+                self.token(",")?;
+            }
+            *byte = self.field(2)? as u8;
+        }
+
+        Ok(Uuid::from_fields(d1, d2, d3, &d4)
+            .expect("found the wrong number of bytes while parsing a GUID"))
+    }
+}
+
+impl Initializer {
+    /// The length of a C struct initializer [`Uuid`] string.
+    ///
+    /// [`Uuid`]: ../struct.Uuid.html
+    pub const LENGTH: usize = 78;
+
+    /// Creates an [`Initializer`] from a [`Uuid`].
+    ///
+    /// [`Uuid`]: ../struct.Uuid.html
+    /// [`Initializer`]: struct.Initializer.html
+    pub const fn from_uuid(uuid: Uuid) -> Self {
+        Initializer(uuid)
+    }
+
+    /// Writes the [`Uuid`] as a C struct initializer with lower-case hex
+    /// digits to `buffer`, and returns the subslice of the buffer that
+    /// contains the encoded UUID.
+    ///
+    /// [`Uuid`]: ../struct.Uuid.html
+    ///
+    /// # Panics
+    ///
+    /// Panics if the buffer is not large enough: it must have length at least
+    /// [`LENGTH`].
+    ///
+    /// [`LENGTH`]: #associatedconstant.LENGTH
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use uuid::{adapter::Initializer, Uuid};
+    ///
+    /// fn main() -> Result<(), uuid::Error> {
+    ///     let uuid = Uuid::parse_str("6b29fc40-ca47-1067-b31d-00dd010662da")?;
+    ///
+    ///     assert_eq!(
+    ///         uuid.to_initializer()
+    ///             .encode_lower(&mut [0; Initializer::LENGTH]),
+    ///         "{0x6b29fc40, 0xca47, 0x1067, \
+    ///           {0xb3, 0x1d, 0x00, 0xdd, 0x01, 0x06, 0x62, 0xda}}"
+    ///     );
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn encode_lower<'buf>(&self, buffer: &'buf mut [u8]) -> &'buf mut str {
+        encode(buffer, &self.0, false, true)
+    }
+
+    /// Writes the [`Uuid`] as a C struct initializer with upper-case hex
+    /// digits to `buffer`, and returns the subslice of the buffer that
+    /// contains the encoded UUID.
+    ///
+    /// The `0x` prefixes stay lower-case, as in the Windows SDK headers.
+    ///
+    /// [`Uuid`]: ../struct.Uuid.html
+    ///
+    /// # Panics
+    ///
+    /// Panics if the buffer is not large enough: it must have length at least
+    /// [`LENGTH`].
+    ///
+    /// [`LENGTH`]: #associatedconstant.LENGTH
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use uuid::{adapter::Initializer, Uuid};
+    ///
+    /// fn main() -> Result<(), uuid::Error> {
+    ///     let uuid = Uuid::parse_str("6b29fc40-ca47-1067-b31d-00dd010662da")?;
+    ///
+    ///     assert_eq!(
+    ///         uuid.to_initializer()
+    ///             .encode_upper(&mut [0; Initializer::LENGTH]),
+    ///         "{0x6B29FC40, 0xCA47, 0x1067, \
+    ///           {0xB3, 0x1D, 0x00, 0xDD, 0x01, 0x06, 0x62, 0xDA}}"
+    ///     );
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn encode_upper<'buf>(&self, buffer: &'buf mut [u8]) -> &'buf mut str {
+        encode(buffer, &self.0, true, true)
+    }
+
+    /// Parses a C struct initializer for a `GUID`.
+    ///
+    /// Whitespace, including newlines, may appear between any of the
+    /// tokens. Each field is a hex literal of up to its width in digits,
+    /// in any case, optionally followed by an integer suffix like `L`.
+    /// This is the same as parsing with [`FromStr`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use uuid::{adapter::Initializer, Uuid};
+    ///
+    /// let initializer = Initializer::parse_str(
+    ///     "{ 0x6B29FC40L, 0xCA47, 0x1067,
+    ///        { 0xB3, 0x1D, 0x0, 0xDD, 0x1, 0x6, 0x62, 0xDA } }",
+    /// )?;
+    ///
+    /// assert_eq!(
+    ///     initializer.into_inner(),
+    ///     Uuid::parse_str("6b29fc40-ca47-1067-b31d-00dd010662da")?
+    /// );
+    /// # Ok::<(), uuid::Error>(())
+    /// ```
+    ///
+    /// [`FromStr`]: https://doc.rust-lang.org/std/str/trait.FromStr.html
+    pub fn parse_str(input: &str) -> Result<Self, crate::Error> {
+        let mut parser = Parser::new(input);
+
+        parser.token("{")?;
+        let uuid = parser.fields(true)?;
+        parser.token("}")?;
+        parser.token("}")?;
+        parser.end()?;
+
+        Ok(Initializer(uuid))
+    }
+
+    /// Consumes the [`Initializer`], returning the underlying [`Uuid`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use uuid::Uuid;
+    ///
+    /// let initializer = Uuid::nil().to_initializer();
+    /// assert_eq!(initializer.into_inner(), Uuid::nil());
+    /// ```
+    ///
+    /// [`Initializer`]: struct.Initializer.html
+    /// [`Uuid`]: ../struct.Uuid.html
+    pub const fn into_inner(self) -> Uuid {
+        self.0
+    }
+}
+
+impl<'a> InitializerRef<'a> {
+    /// The length of a C struct initializer [`Uuid`] string.
+    ///
+    /// [`Uuid`]: ../struct.Uuid.html
+    pub const LENGTH: usize = 78;
+
+    /// Creates an [`InitializerRef`] from a [`Uuid`] reference.
+    ///
+    /// [`Uuid`]: ../struct.Uuid.html
+    /// [`InitializerRef`]: struct.InitializerRef.html
+    pub const fn from_uuid_ref(uuid: &'a Uuid) -> Self {
+        InitializerRef(uuid)
+    }
+
+    /// Writes the [`Uuid`] as a C struct initializer with lower-case hex
+    /// digits to `buffer`, and returns the subslice of the buffer that
+    /// contains the encoded UUID.
+    ///
+    /// [`Uuid`]: ../struct.Uuid.html
+    ///
+    /// # Panics
+    ///
+    /// Panics if the buffer is not large enough: it must have length at least
+    /// [`LENGTH`].
+    ///
+    /// [`LENGTH`]: #associatedconstant.LENGTH
+    pub fn encode_lower<'buf>(&self, buffer: &'buf mut [u8]) -> &'buf mut str {
+        encode(buffer, self.0, false, true)
+    }
+
+    /// Writes the [`Uuid`] as a C struct initializer with upper-case hex
+    /// digits to `buffer`, and returns the subslice of the buffer that
+    /// contains the encoded UUID.
+    ///
+    /// [`Uuid`]: ../struct.Uuid.html
+    ///
+    /// # Panics
+    ///
+    /// Panics if the buffer is not large enough: it must have length at least
+    /// [`LENGTH`].
+    ///
+    /// [`LENGTH`]: #associatedconstant.LENGTH
+    pub fn encode_upper<'buf>(&self, buffer: &'buf mut [u8]) -> &'buf mut str {
+        encode(buffer, self.0, true, true)
+    }
+
+    /// Consumes the [`InitializerRef`], returning the underlying [`Uuid`]
+    /// reference.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use uuid::Uuid;
+    ///
+    /// let uuid = Uuid::nil();
+    /// let initializer = uuid.to_initializer_ref();
+    /// assert_eq!(initializer.into_inner(), &uuid);
+    /// ```
+    ///
+    /// [`InitializerRef`]: struct.InitializerRef.html
+    /// [`Uuid`]: ../struct.Uuid.html
+    pub const fn into_inner(self) -> &'a Uuid {
+        self.0
+    }
+}
+
+impl FromStr for Initializer {
+    type Err = crate::Error;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        Initializer::parse_str(input)
+    }
+}
+
+impl<'a> TryFrom<&'a str> for Initializer {
+    type Error = crate::Error;
+
+    fn try_from(input: &'a str) -> Result<Self, Self::Error> {
+        Initializer::parse_str(input)
+    }
+}
+
+/// A `DEFINE_GUID` macro invocation from the Windows SDK, which declares a
+/// named `GUID` constant.
+///
+/// Formatting this writes the invocation with its trailing semicolon.
+///
+/// # Examples
+///
+/// ```rust
+/// use uuid::{adapter::DefineGuid, Uuid};
+///
+/// let uuid = Uuid::parse_str("6b29fc40-ca47-1067-b31d-00dd010662da")?;
+///
+/// assert_eq!(
+///     format!("{:X}", DefineGuid::new("IID_IExample", uuid)),
+///     "DEFINE_GUID(IID_IExample, 0x6B29FC40, 0xCA47, 0x1067, \
+///      0xB3, 0x1D, 0x00, 0xDD, 0x01, 0x06, 0x62, 0xDA);"
+/// );
+/// # Ok::<(), uuid::Error>(())
+/// ```
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct DefineGuid<'a> {
+    name: &'a str,
+    uuid: Uuid,
+}
+
+impl<'a> DefineGuid<'a> {
+    /// Creates a `DEFINE_GUID` invocation declaring `name` as `uuid`.
+    ///
+    /// `name` should be a valid C identifier.
+    pub const fn new(name: &'a str, uuid: Uuid) -> Self {
+        DefineGuid { name, uuid }
+    }
+
+    /// The name of the declared `GUID`.
+    pub const fn name(&self) -> &'a str {
+        self.name
+    }
+
+    /// The value of the declared `GUID`.
+    pub const fn uuid(&self) -> Uuid {
+        self.uuid
+    }
+
+    /// Parses a `DEFINE_GUID` invocation, with or without its trailing
+    /// semicolon.
+    ///
+    /// The fields are parsed like those of [`Initializer::parse_str`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use uuid::{adapter::DefineGuid, Uuid};
+    ///
+    /// let define = DefineGuid::parse_str(
+    ///     "DEFINE_GUID(IID_IUnknown, 0x00000000, 0x0000, 0x0000,
+    ///                  0xC0, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x46);",
+    /// )?;
+    ///
+    /// assert_eq!(define.name(), "IID_IUnknown");
+    /// assert_eq!(
+    ///     define.uuid(),
+    ///     Uuid::parse_str("00000000-0000-0000-c000-000000000046")?
+    /// );
+    /// # Ok::<(), uuid::Error>(())
+    /// ```
+    ///
+    /// [`Initializer::parse_str`]: struct.Initializer.html#method.parse_str
+    pub fn parse_str(input: &'a str) -> Result<Self, crate::Error> {
+        let mut parser = Parser::new(input);
+
+        parser.token("DEFINE_GUID")?;
+        parser.token("(")?;
+        let name = parser.identifier()?;
+        parser.token(",")?;
+        let uuid = parser.fields(false)?;
+        parser.token(")")?;
+
+        parser.skip_whitespace();
+        if parser.rest().starts_with(';') {
+            parser.index += 1;
+        }
+        parser.end()?;
+
+        Ok(DefineGuid { name, uuid })
+    }
+
+    fn fmt(&self, f: &mut fmt::Formatter<'_>, upper: bool) -> fmt::Result {
+        write!(
+            f,
+            "DEFINE_GUID({}, {});",
+            self.name,
+            encode(&mut [0; FLAT_LENGTH], &self.uuid, upper, false)
+        )
+    }
+}
+
+impl<'a> fmt::Display for DefineGuid<'a> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::LowerHex::fmt(self, f)
+    }
+}
+
+impl<'a> fmt::LowerHex for DefineGuid<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        DefineGuid::fmt(self, f, false)
+    }
+}
+
+impl<'a> fmt::UpperHex for DefineGuid<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        DefineGuid::fmt(self, f, true)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::std::string::ToString;
+    use crate::test_util;
+
+    const INITIALIZER: &str = "{0x6b29fc40, 0xca47, 0x1067, {0xb3, 0x1d, \
+                               0x00, 0xdd, 0x01, 0x06, 0x62, 0x0b}}";
+    const DEFINE_GUID: &str = "DEFINE_GUID(IID_IExample, 0x6b29fc40, 0xca47, \
+                               0x1067, 0xb3, 0x1d, 0x00, 0xdd, 0x01, 0x06, \
+                               0x62, 0x0b);";
+
+    fn expected() -> Uuid {
+        Uuid::parse_str("6b29fc40-ca47-1067-b31d-00dd0106620b").unwrap()
+    }
+
+    fn parse_err(input: &str) -> Error {
+        Initializer::parse_str(input).unwrap_err().expect_parser()
+    }
+
+    #[test]
+    fn test_encode() {
+        assert_eq!(expected().to_initializer().to_string(), INITIALIZER);
+        assert_eq!(
+            format!("{:X}", expected().to_initializer_ref()),
+            INITIALIZER.to_uppercase().replace("0X", "0x")
+        );
+        assert_eq!(
+            DefineGuid::new("IID_IExample", expected()).to_string(),
+            DEFINE_GUID
+        );
+
+        let mut buf = [b'x'; 100];
+        let len = Uuid::nil().to_initializer().encode_lower(&mut buf).len();
+        assert_eq!(len, Initializer::LENGTH);
+        assert!(buf[len..].iter().all(|x| *x == b'x'));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_encode_too_small() {
+        Uuid::nil().to_initializer().encode_lower(&mut [0; 77]);
+    }
+
+    #[test]
+    fn test_round_trip() {
+        for uuid in &[test_util::new(), test_util::new2(), Uuid::nil()] {
+            let initializer = uuid.to_initializer();
+
+            assert_eq!(
+                Initializer::parse_str(&format!("{:x}", initializer)),
+                Ok(initializer)
+            );
+            assert_eq!(
+                format!("{:X}", initializer).parse::<Initializer>(),
+                Ok(initializer)
+            );
+
+            let define = DefineGuid::new("CLSID_Test", *uuid);
+            assert_eq!(DefineGuid::parse_str(&define.to_string()), Ok(define));
+        }
+    }
+
+    #[test]
+    fn test_parse_lenient() {
+        assert_eq!(
+            Initializer::parse_str(
+                " {\n\t0x6B29FC40L , 0Xca47,0x1067 ,{0xb3,0x1d,0x0,0xdd,\
+                 0x1,0x6,0x62,0xbU}\n}\r\n"
+            )
+            .map(Uuid::from),
+            Ok(expected())
+        );
+
+        let define = DefineGuid::parse_str(
+            "DEFINE_GUID( _iid2, 0x6b29fc40, 0xca47, 0x1067, \
+             0xb3, 0x1d, 0x00, 0xdd, 0x01, 0x06, 0x62, 0x0b )",
+        )
+        .unwrap();
+        assert_eq!(define.name(), "_iid2");
+        assert_eq!(define.uuid(), expected());
+    }
+
+    #[test]
+    fn test_parse_invalid() {
+        assert_eq!(
+            parse_err("{0x6b29fc40, 0xca47, 0x1067, 0xb3}"),
+            Error::InvalidCharacter {
+                expected: "{",
+                found: '0',
+                index: 29,
+                start: 0,
+                urn: error::UrnPrefix::NotApplicable,
+            }
+        );
+        assert_eq!(
+            parse_err("{0x6b29fc400, 0xca47}"),
+            Error::InvalidCharacter {
+                expected: "at most 8 hex digits",
+                found: '0',
+                index: 11,
+                start: 0,
+                urn: error::UrnPrefix::NotApplicable,
+            }
+        );
+        assert_eq!(
+            parse_err("{0x6b29fc4O, 0xca47}"),
+            Error::InvalidCharacter {
+                expected: ",",
+                found: 'O',
+                index: 10,
+                start: 0,
+                urn: error::UrnPrefix::NotApplicable,
+            }
+        );
+        assert_eq!(
+            parse_err("{6b29fc40}"),
+            Error::InvalidCharacter {
+                expected: "0x",
+                found: '6',
+                index: 1,
+                start: 0,
+                urn: error::UrnPrefix::NotApplicable,
+            }
+        );
+        assert_eq!(
+            parse_err("{0x6b29fc40, 0xca47, 0x1067, {0xb3"),
+            Error::UnexpectedEnd {
+                expected: ",",
+                index: 34,
+            }
+        );
+
+        let truncated = &INITIALIZER[..INITIALIZER.len() - 1];
+        assert_eq!(
+            parse_err(truncated),
+            Error::UnexpectedEnd {
+                expected: "}",
+                index: truncated.len(),
+            }
+        );
+        assert_eq!(
+            Initializer::parse_str(truncated)
+                .unwrap_err()
+                .render(truncated)
+                .to_string(),
+            format!(
+                "\
+error: unexpected end of input: expected }} at 77
+  | {}
+  | {:77}^",
+                truncated, ""
+            )
+        );
+
+        assert_eq!(
+            parse_err(&format!("{};", INITIALIZER)),
+            Error::InvalidCharacter {
+                expected: "the end of the input",
+                found: ';',
+                index: INITIALIZER.len(),
+                start: 0,
+                urn: error::UrnPrefix::NotApplicable,
+            }
+        );
+
+        assert_eq!(
+            DefineGuid::parse_str("DEFINE_GUID(1D, 0x0)")
+                .unwrap_err()
+                .expect_parser(),
+            Error::InvalidCharacter {
+                expected: "a C identifier",
+                found: '1',
+                index: 12,
+                start: 0,
+                urn: error::UrnPrefix::NotApplicable,
+            }
+        );
+        assert!(DefineGuid::parse_str(INITIALIZER).is_err());
+        assert!(Initializer::parse_str(DEFINE_GUID).is_err());
+    }
+
+    #[test]
+    fn test_initializer_to_inner() {
+        let uuid = expected();
+
+        assert_eq!(Uuid::from(uuid.to_initializer()), uuid);
+        assert_eq!(<&Uuid>::from(uuid.to_initializer_ref()), &uuid);
+        assert_eq!(uuid.to_initializer_ref().into_inner(), &uuid);
+    }
+}
@@ -0,0 +1,185 @@
+// Copyright 2013-2014 The Rust Project Developers.
+// Copyright 2018 The Uuid Project Developers.
+//
+// See the COPYRIGHT file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Encoding many UUIDs at once.
+
+use super::{Braced, Hyphenated, Simple, Urn};
+use crate::std::vec::Vec;
+use crate::Uuid;
+
+/// The string formats a [`Uuid`] can be encoded in by [`encode_many`].
+///
+/// [`Uuid`]: ../struct.Uuid.html
+/// [`encode_many`]: fn.encode_many.html
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Format {
+    /// The simple format, like [`Simple`].
+    ///
+    /// [`Simple`]: struct.Simple.html
+    Simple,
+    /// The hyphenated format, like [`Hyphenated`].
+    ///
+    /// [`Hyphenated`]: struct.Hyphenated.html
+    Hyphenated,
+    /// The urn format, like [`Urn`].
+    ///
+    /// [`Urn`]: struct.Urn.html
+    Urn,
+    /// The braced format, like [`Braced`].
+    ///
+    /// [`Braced`]: struct.Braced.html
+    Braced,
+}
+
+impl Format {
+    /// The length of a [`Uuid`] string in this format.
+    ///
+    /// [`Uuid`]: ../struct.Uuid.html
+    pub const fn encoded_len(self) -> usize {
+        match self {
+            Format::Simple => Simple::LENGTH,
+            Format::Hyphenated => Hyphenated::LENGTH,
+            Format::Urn => Urn::LENGTH,
+            Format::Braced => Braced::LENGTH,
+        }
+    }
+}
+
+/// Appends each of the `uuids` to `buffer` as a lower-case string in the
+/// given `format`, with `separator` between them.
+///
+/// `buffer` is grown once to fit every UUID, and each one is then encoded
+/// in place, so this is more efficient than formatting them one at a time.
+///
+/// # Examples
+///
+/// ```rust
+/// use uuid::{adapter, Uuid};
+///
+/// let uuids = [
+///     Uuid::parse_str("67e55044-10b1-426f-9247-bb680e5fe0c8")?,
+///     Uuid::nil(),
+/// ];
+///
+/// let mut buffer = Vec::new();
+/// adapter::encode_many(&uuids, adapter::Format::Simple, &mut buffer, "\n");
+///
+/// assert_eq!(
+///     buffer,
+///     b"67e5504410b1426f9247bb680e5fe0c8\n00000000000000000000000000000000"
+/// );
+/// # Ok::<(), uuid::Error>(())
+/// ```
+pub fn encode_many(
+    uuids: &[Uuid],
+    format: Format,
+    buffer: &mut Vec<u8>,
+    separator: &str,
+) {
+    if uuids.is_empty() {
+        return;
+    }
+
+    let separator = separator.as_bytes();
+    let len = format.encoded_len();
+
+    let start = buffer.len();
+    buffer.resize(
+        start + uuids.len() * len + (uuids.len() - 1) * separator.len(),
+        0,
+    );
+
+    let mut rest = &mut buffer[start..];
+    for (i, uuid) in uuids.iter().enumerate() {
+        if i > 0 {
+            let (before, after) = rest.split_at_mut(separator.len());
+            before.copy_from_slice(separator);
+            rest = after;
+        }
+
+        let (encoded, after) = rest.split_at_mut(len);
+        match format {
+            Format::Simple => uuid.to_simple_ref().encode_lower(encoded),
+            Format::Hyphenated => {
+                uuid.to_hyphenated_ref().encode_lower(encoded)
+            }
+            Format::Urn => uuid.to_urn_ref().encode_lower(encoded),
+            Format::Braced => uuid.to_braced_ref().encode_lower(encoded),
+        };
+        rest = after;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::std::string::{String, ToString};
+    use crate::test_util;
+
+    #[test]
+    fn test_encode_many() {
+        let uuids = [test_util::new(), test_util::new2(), Uuid::nil()];
+
+        for &(format, encode) in &[
+            (
+                Format::Simple,
+                (|uuid| uuid.to_simple().to_string()) as fn(&Uuid) -> String,
+            ),
+            (Format::Hyphenated, |uuid| uuid.to_hyphenated().to_string()),
+        ] {
+            let mut buffer = b"header,".to_vec();
+            encode_many(&uuids, format, &mut buffer, ", ");
+
+            let expected =
+                uuids.iter().map(encode).collect::<Vec<_>>().join(", ");
+
+            assert_eq!(
+                String::from_utf8(buffer).unwrap(),
+                format!("header,{}", expected)
+            );
+        }
+
+        let mut buffer = Vec::new();
+        encode_many(&uuids, Format::Urn, &mut buffer, "");
+        assert_eq!(
+            String::from_utf8(buffer).unwrap(),
+            format!(
+                "{}{}{}",
+                uuids[0].to_urn(),
+                uuids[1].to_urn(),
+                uuids[2].to_urn()
+            )
+        );
+
+        let mut buffer = Vec::new();
+        encode_many(&uuids[..1], Format::Braced, &mut buffer, "\n");
+        assert_eq!(
+            String::from_utf8(buffer).unwrap(),
+            uuids[0].to_braced().to_string()
+        );
+    }
+
+    #[test]
+    fn test_encode_many_empty() {
+        let mut buffer = b"unchanged".to_vec();
+        encode_many(&[], Format::Hyphenated, &mut buffer, "\n");
+
+        assert_eq!(buffer, b"unchanged");
+    }
+
+    #[test]
+    fn test_format_encoded_len() {
+        assert_eq!(Format::Simple.encoded_len(), Simple::LENGTH);
+        assert_eq!(Format::Hyphenated.encoded_len(), Hyphenated::LENGTH);
+        assert_eq!(Format::Urn.encoded_len(), Urn::LENGTH);
+        assert_eq!(Format::Braced.encoded_len(), Braced::LENGTH);
+    }
+}
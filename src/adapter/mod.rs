@@ -16,6 +16,15 @@ use crate::std::{borrow::Borrow, fmt, str};
 
 #[cfg(feature = "serde")]
 pub mod compact;
+mod initializer;
+#[cfg(feature = "std")]
+mod many;
+mod simd;
+mod utf16;
+
+pub use self::initializer::DefineGuid;
+#[cfg(feature = "std")]
+pub use self::many::{encode_many, Format};
 
 /// An adaptor for formatting an [`Uuid`] as a hyphenated string.
 ///
@@ -65,6 +74,42 @@ pub struct Urn(Uuid);
 #[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
 pub struct UrnRef<'a>(&'a Uuid);
 
+/// An adaptor for formatting an [`Uuid`] as a braced string, as used by
+/// Microsoft tools such as the Windows registry and .NET.
+///
+/// Takes an owned instance of the [`Uuid`].
+///
+/// [`Uuid`]: ../struct.Uuid.html
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Braced(Uuid);
+
+/// An adaptor for formatting an [`Uuid`] as a braced string, as used by
+/// Microsoft tools such as the Windows registry and .NET.
+///
+/// Takes a reference of the [`Uuid`].
+///
+/// [`Uuid`]: ../struct.Uuid.html
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct BracedRef<'a>(&'a Uuid);
+
+/// An adaptor for formatting an [`Uuid`] as a C struct initializer for a
+/// Windows `GUID`, as found in Windows SDK headers.
+///
+/// Takes an owned instance of the [`Uuid`].
+///
+/// [`Uuid`]: ../struct.Uuid.html
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Initializer(Uuid);
+
+/// An adaptor for formatting an [`Uuid`] as a C struct initializer for a
+/// Windows `GUID`, as found in Windows SDK headers.
+///
+/// Takes a reference of the [`Uuid`].
+///
+/// [`Uuid`]: ../struct.Uuid.html
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct InitializerRef<'a>(&'a Uuid);
+
 impl Uuid {
     /// Get a [`Hyphenated`] formatter.
     ///
@@ -115,6 +160,42 @@ impl Uuid {
     pub const fn to_urn_ref(&self) -> UrnRef<'_> {
         UrnRef::from_uuid_ref(self)
     }
+
+    /// Get a [`Braced`] formatter.
+    ///
+    /// [`Uuid`]: ../struct.Uuid.html
+    /// [`Braced`]: adapter/struct.Braced.html
+    #[inline]
+    pub const fn to_braced(self) -> Braced {
+        Braced::from_uuid(self)
+    }
+
+    /// Get a borrowed [`BracedRef`] formatter.
+    ///
+    /// [`Uuid`]: ../struct.Uuid.html
+    /// [`BracedRef`]: adapter/struct.BracedRef.html
+    #[inline]
+    pub const fn to_braced_ref(&self) -> BracedRef<'_> {
+        BracedRef::from_uuid_ref(self)
+    }
+
+    /// Get an [`Initializer`] formatter.
+    ///
+    /// [`Uuid`]: ../struct.Uuid.html
+    /// [`Initializer`]: adapter/struct.Initializer.html
+    #[inline]
+    pub const fn to_initializer(self) -> Initializer {
+        Initializer::from_uuid(self)
+    }
+
+    /// Get a borrowed [`InitializerRef`] formatter.
+    ///
+    /// [`Uuid`]: ../struct.Uuid.html
+    /// [`InitializerRef`]: adapter/struct.InitializerRef.html
+    #[inline]
+    pub const fn to_initializer_ref(&self) -> InitializerRef<'_> {
+        InitializerRef::from_uuid_ref(self)
+    }
 }
 
 const UPPER: [u8; 16] = [
@@ -138,7 +219,6 @@ const HYPHEN_POSITIONS: [usize; 4] = [8, 13, 18, 23];
 /// The `start` parameter allows writing a prefix (such as
 /// "urn:uuid:") to the buffer that's included in the final encoded
 /// UUID.
-#[allow(clippy::needless_range_loop)]
 fn encode<'a>(
     full_buffer: &'a mut [u8],
     start: usize,
@@ -152,29 +232,53 @@ fn encode<'a>(
         let buffer = &mut full_buffer[start..start + len];
         let bytes = uuid.as_bytes();
 
-        let hex = if upper { &UPPER } else { &LOWER };
+        if !simd::encode(buffer, bytes, hyphens, upper) {
+            encode_scalar(buffer, bytes, hyphens, upper);
+        }
+    }
 
-        for group in 0..5 {
-            // If we're writing hyphens, we need to shift the output
-            // location along by how many of them have been written
-            // before this point. That's exactly the (0-indexed) group
-            // number.
-            let hyphens_before = if hyphens { group } else { 0 };
-            for idx in BYTE_POSITIONS[group]..BYTE_POSITIONS[group + 1] {
-                let b = bytes[idx];
-                let out_idx = hyphens_before + 2 * idx;
+    str::from_utf8_mut(&mut full_buffer[..start + len])
+        .expect("found non-ASCII output characters while encoding a UUID")
+}
 
-                buffer[out_idx] = hex[(b >> 4) as usize];
-                buffer[out_idx + 1] = hex[(b & 0b1111) as usize];
-            }
+/// Encodes `bytes` possibly with hyphens, and possibly in upper case, to
+/// buffer one nibble at a time.
+#[allow(clippy::needless_range_loop)]
+fn encode_scalar(buffer: &mut [u8], bytes: &Bytes, hyphens: bool, upper: bool) {
+    let hex = if upper { &UPPER } else { &LOWER };
+
+    for group in 0..5 {
+        // If we're writing hyphens, we need to shift the output
+        // location along by how many of them have been written
+        // before this point. That's exactly the (0-indexed) group
+        // number.
+        let hyphens_before = if hyphens { group } else { 0 };
+        for idx in BYTE_POSITIONS[group]..BYTE_POSITIONS[group + 1] {
+            let b = bytes[idx];
+            let out_idx = hyphens_before + 2 * idx;
+
+            buffer[out_idx] = hex[(b >> 4) as usize];
+            buffer[out_idx + 1] = hex[(b & 0b1111) as usize];
+        }
 
-            if group != 4 && hyphens {
-                buffer[HYPHEN_POSITIONS[group]] = b'-';
-            }
+        if group != 4 && hyphens {
+            buffer[HYPHEN_POSITIONS[group]] = b'-';
         }
     }
+}
 
-    str::from_utf8_mut(&mut full_buffer[..start + len])
+/// Encodes the `uuid` with hyphens between braces, possibly in upper case,
+/// to buffer and returns the str sliced from buffer[..38].
+fn encode_braced<'a>(
+    buffer: &'a mut [u8],
+    uuid: &Uuid,
+    upper: bool,
+) -> &'a mut str {
+    buffer[0] = b'{';
+    buffer[37] = b'}';
+    encode(buffer, 1, uuid, true, upper);
+
+    str::from_utf8_mut(&mut buffer[..38])
         .expect("found non-ASCII output characters while encoding a UUID")
 }
 
@@ -927,6 +1031,242 @@ impl<'a> UrnRef<'a> {
     }
 }
 
+impl Braced {
+    /// The length of a braced [`Uuid`] string.
+    ///
+    /// [`Uuid`]: ../struct.Uuid.html
+    pub const LENGTH: usize = 38;
+
+    /// Creates a [`Braced`] from a [`Uuid`].
+    ///
+    /// [`Uuid`]: ../struct.Uuid.html
+    /// [`Braced`]: struct.Braced.html
+    pub const fn from_uuid(uuid: Uuid) -> Self {
+        Braced(uuid)
+    }
+
+    /// Writes the [`Uuid`] as a lower-case braced string to
+    /// `buffer`, and returns the subslice of the buffer that contains the
+    /// encoded UUID.
+    ///
+    /// This is slightly more efficient than using the formatting
+    /// infrastructure as it avoids virtual calls, and may avoid
+    /// double buffering.
+    ///
+    /// [`Uuid`]: ../struct.Uuid.html
+    ///
+    /// # Panics
+    ///
+    /// Panics if the buffer is not large enough: it must have length at least
+    /// [`LENGTH`]. [`Uuid::encode_buffer`] can be used to get a
+    /// sufficiently-large temporary buffer.
+    ///
+    /// [`LENGTH`]: #associatedconstant.LENGTH
+    /// [`Uuid::encode_buffer`]: ../struct.Uuid.html#method.encode_buffer
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use uuid::Uuid;
+    ///
+    /// fn main() -> Result<(), uuid::Error> {
+    ///     let uuid = Uuid::parse_str("936da01f9abd4d9d80c702af85c822a8")?;
+    ///
+    ///     // the encoded portion is returned
+    ///     assert_eq!(
+    ///         uuid.to_braced().encode_lower(&mut Uuid::encode_buffer()),
+    ///         "{936da01f-9abd-4d9d-80c7-02af85c822a8}"
+    ///     );
+    ///
+    ///     // the buffer is mutated directly, and trailing contents remains
+    ///     let mut buf = [b'!'; 42];
+    ///     uuid.to_braced().encode_lower(&mut buf);
+    ///     assert_eq!(
+    ///         &buf as &[_],
+    ///         b"{936da01f-9abd-4d9d-80c7-02af85c822a8}!!!!" as &[_]
+    ///     );
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn encode_lower<'buf>(&self, buffer: &'buf mut [u8]) -> &'buf mut str {
+        encode_braced(buffer, &self.0, false)
+    }
+
+    /// Writes the [`Uuid`] as a upper-case braced string to
+    /// `buffer`, and returns the subslice of the buffer that contains the
+    /// encoded UUID.
+    ///
+    /// This is slightly more efficient than using the formatting
+    /// infrastructure as it avoids virtual calls, and may avoid
+    /// double buffering.
+    ///
+    /// [`Uuid`]: ../struct.Uuid.html
+    ///
+    /// # Panics
+    ///
+    /// Panics if the buffer is not large enough: it must have length at least
+    /// [`LENGTH`]. [`Uuid::encode_buffer`] can be used to get a
+    /// sufficiently-large temporary buffer.
+    ///
+    /// [`LENGTH`]: #associatedconstant.LENGTH
+    /// [`Uuid::encode_buffer`]: ../struct.Uuid.html#method.encode_buffer
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use uuid::Uuid;
+    ///
+    /// fn main() -> Result<(), uuid::Error> {
+    ///     let uuid = Uuid::parse_str("936da01f9abd4d9d80c702af85c822a8")?;
+    ///
+    ///     // the encoded portion is returned
+    ///     assert_eq!(
+    ///         uuid.to_braced().encode_upper(&mut Uuid::encode_buffer()),
+    ///         "{936DA01F-9ABD-4D9D-80C7-02AF85C822A8}"
+    ///     );
+    ///
+    ///     // the buffer is mutated directly, and trailing contents remains
+    ///     let mut buf = [b'!'; 42];
+    ///     uuid.to_braced().encode_upper(&mut buf);
+    ///     assert_eq!(
+    ///         &buf as &[_],
+    ///         b"{936DA01F-9ABD-4D9D-80C7-02AF85C822A8}!!!!" as &[_]
+    ///     );
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn encode_upper<'buf>(&self, buffer: &'buf mut [u8]) -> &'buf mut str {
+        encode_braced(buffer, &self.0, true)
+    }
+
+    /// Consumes the [`Braced`], returning the underlying [`Uuid`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use uuid::Uuid;
+    ///
+    /// let braced = Uuid::nil().to_braced();
+    /// assert_eq!(braced.into_inner(), Uuid::nil());
+    /// ```
+    pub const fn into_inner(self) -> Uuid {
+        self.0
+    }
+}
+
+impl<'a> BracedRef<'a> {
+    /// The length of a braced [`Uuid`] string.
+    ///
+    /// [`Uuid`]: ../struct.Uuid.html
+    pub const LENGTH: usize = 38;
+
+    /// Creates a [`BracedRef`] from a [`Uuid`] reference.
+    ///
+    /// [`Uuid`]: ../struct.Uuid.html
+    /// [`BracedRef`]: struct.BracedRef.html
+    pub const fn from_uuid_ref(uuid: &'a Uuid) -> Self {
+        BracedRef(uuid)
+    }
+
+    /// Writes the [`Uuid`] as a lower-case braced string to
+    /// `buffer`, and returns the subslice of the buffer that contains the
+    /// encoded UUID.
+    ///
+    /// This is slightly more efficient than using the formatting
+    /// infrastructure as it avoids virtual calls, and may avoid
+    /// double buffering.
+    ///
+    /// [`Uuid`]: ../struct.Uuid.html
+    ///
+    /// # Panics
+    ///
+    /// Panics if the buffer is not large enough: it must have length at least
+    /// [`LENGTH`]. [`Uuid::encode_buffer`] can be used to get a
+    /// sufficiently-large temporary buffer.
+    ///
+    /// [`LENGTH`]: #associatedconstant.LENGTH
+    /// [`Uuid::encode_buffer`]: ../struct.Uuid.html#method.encode_buffer
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use uuid::Uuid;
+    ///
+    /// fn main() -> Result<(), uuid::Error> {
+    ///     let uuid = Uuid::parse_str("936da01f9abd4d9d80c702af85c822a8")?;
+    ///
+    ///     // the encoded portion is returned
+    ///     assert_eq!(
+    ///         uuid.to_braced_ref().encode_lower(&mut Uuid::encode_buffer()),
+    ///         "{936da01f-9abd-4d9d-80c7-02af85c822a8}"
+    ///     );
+    ///
+    ///     // the buffer is mutated directly, and trailing contents remains
+    ///     let mut buf = [b'!'; 42];
+    ///     uuid.to_braced_ref().encode_lower(&mut buf);
+    ///     assert_eq!(
+    ///         &buf as &[_],
+    ///         b"{936da01f-9abd-4d9d-80c7-02af85c822a8}!!!!" as &[_]
+    ///     );
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn encode_lower<'buf>(&self, buffer: &'buf mut [u8]) -> &'buf mut str {
+        encode_braced(buffer, self.0, false)
+    }
+
+    /// Writes the [`Uuid`] as a upper-case braced string to
+    /// `buffer`, and returns the subslice of the buffer that contains the
+    /// encoded UUID.
+    ///
+    /// This is slightly more efficient than using the formatting
+    /// infrastructure as it avoids virtual calls, and may avoid
+    /// double buffering.
+    ///
+    /// [`Uuid`]: ../struct.Uuid.html
+    ///
+    /// # Panics
+    ///
+    /// Panics if the buffer is not large enough: it must have length at least
+    /// [`LENGTH`]. [`Uuid::encode_buffer`] can be used to get a
+    /// sufficiently-large temporary buffer.
+    ///
+    /// [`LENGTH`]: #associatedconstant.LENGTH
+    /// [`Uuid::encode_buffer`]: ../struct.Uuid.html#method.encode_buffer
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use uuid::Uuid;
+    ///
+    /// fn main() -> Result<(), uuid::Error> {
+    ///     let uuid = Uuid::parse_str("936da01f9abd4d9d80c702af85c822a8")?;
+    ///
+    ///     // the encoded portion is returned
+    ///     assert_eq!(
+    ///         uuid.to_braced_ref().encode_upper(&mut Uuid::encode_buffer()),
+    ///         "{936DA01F-9ABD-4D9D-80C7-02AF85C822A8}"
+    ///     );
+    ///
+    ///     // the buffer is mutated directly, and trailing contents remains
+    ///     let mut buf = [b'!'; 42];
+    ///     uuid.to_braced_ref().encode_upper(&mut buf);
+    ///     assert_eq!(
+    ///         &buf as &[_],
+    ///         b"{936DA01F-9ABD-4D9D-80C7-02AF85C822A8}!!!!" as &[_]
+    ///     );
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn encode_upper<'buf>(&self, buffer: &'buf mut [u8]) -> &'buf mut str {
+        encode_braced(buffer, self.0, true)
+    }
+}
+
 macro_rules! impl_adapter_traits {
     ($($T:ident<$($a:lifetime),*>),+) => {$(
         impl<$($a),*> fmt::Display for $T<$($a),*> {
@@ -1021,7 +1361,11 @@ impl_adapter_traits! {
     Simple<>,
     SimpleRef<'a>,
     Urn<>,
-    UrnRef<'a>
+    UrnRef<'a>,
+    Braced<>,
+    BracedRef<'a>,
+    Initializer<>,
+    InitializerRef<'a>
 }
 
 #[cfg(test)]
@@ -1076,6 +1420,22 @@ mod tests {
         assert!(buf[len..].iter().all(|x| *x == b'x'));
     }
 
+    #[test]
+    fn braced_trailing() {
+        let mut buf = [b'x'; 100];
+        let len = Uuid::nil().to_braced().encode_lower(&mut buf).len();
+        assert_eq!(len, super::Braced::LENGTH);
+        assert!(buf[len..].iter().all(|x| *x == b'x'));
+    }
+
+    #[test]
+    fn braced_ref_trailing() {
+        let mut buf = [b'x'; 100];
+        let len = Uuid::nil().to_braced_ref().encode_upper(&mut buf).len();
+        assert_eq!(len, super::BracedRef::LENGTH);
+        assert!(buf[len..].iter().all(|x| *x == b'x'));
+    }
+
     #[test]
     #[should_panic]
     fn hyphenated_too_small() {
@@ -1108,6 +1468,16 @@ mod tests {
     fn urn_ref_too_small() {
         Uuid::nil().to_urn_ref().encode_lower(&mut [0; 44]);
     }
+    #[test]
+    #[should_panic]
+    fn braced_too_small() {
+        Uuid::nil().to_braced().encode_lower(&mut [0; 37]);
+    }
+    #[test]
+    #[should_panic]
+    fn braced_ref_too_small() {
+        Uuid::nil().to_braced_ref().encode_lower(&mut [0; 37]);
+    }
 
     #[test]
     fn hyphenated_to_inner() {
@@ -1149,4 +1519,17 @@ mod tests {
         let borrowed: &Uuid = urn_ref.borrow();
         assert_eq!(borrowed, &Uuid::nil());
     }
+
+    #[test]
+    fn braced_to_inner() {
+        let braced = Uuid::nil().to_braced();
+        assert_eq!(Uuid::from(braced), Uuid::nil());
+    }
+
+    #[test]
+    fn braced_ref_to_inner() {
+        let uuid = Uuid::nil();
+        let braced_ref = uuid.to_braced_ref();
+        assert_eq!(<&Uuid>::from(braced_ref), &Uuid::nil());
+    }
 }
@@ -0,0 +1,332 @@
+//! Vectorized encoding of the simple and hyphenated formats.
+//!
+//! These produce exactly the same output as the scalar encoder, which is
+//! used whenever no vector instructions are available for the target.
+
+/// Writes `bytes` as 32 hex digits, or 36 characters with `hyphens`, to
+/// the start of `buffer` using vector instructions.
+///
+/// Returns `false`, leaving `buffer` untouched, if no vector instructions
+/// are available for the target.
+#[cfg(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    target_feature = "sse2"
+))]
+pub(super) fn encode(
+    buffer: &mut [u8],
+    bytes: &[u8; 16],
+    hyphens: bool,
+    upper: bool,
+) -> bool {
+    #[cfg(feature = "std")]
+    {
+        if hyphens && is_x86_feature_detected!("ssse3") {
+            // SAFETY: SSSE3 is available on this CPU.
+            unsafe { x86::encode_hyphenated_ssse3(buffer, bytes, upper) };
+            return true;
+        }
+    }
+
+    let mut digits = [0u8; 32];
+    // SAFETY: SSE2 is enabled for the target.
+    unsafe { x86::encode_sse2(&mut digits, bytes, upper) };
+
+    if hyphens {
+        hyphenate(buffer, &digits);
+    } else {
+        buffer[..32].copy_from_slice(&digits);
+    }
+
+    true
+}
+
+#[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
+pub(super) fn encode(
+    buffer: &mut [u8],
+    bytes: &[u8; 16],
+    hyphens: bool,
+    upper: bool,
+) -> bool {
+    // SAFETY: NEON is enabled for the target.
+    unsafe { aarch64::encode_neon(buffer, bytes, hyphens, upper) };
+
+    true
+}
+
+#[cfg(not(any(
+    all(
+        any(target_arch = "x86", target_arch = "x86_64"),
+        target_feature = "sse2"
+    ),
+    all(target_arch = "aarch64", target_feature = "neon")
+)))]
+pub(super) fn encode(_: &mut [u8], _: &[u8; 16], _: bool, _: bool) -> bool {
+    false
+}
+
+/// Copies the 32 hex `digits` to `buffer`, with hyphens between the groups.
+#[cfg(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    target_feature = "sse2"
+))]
+fn hyphenate(buffer: &mut [u8], digits: &[u8; 32]) {
+    let buffer = &mut buffer[..36];
+
+    buffer[..8].copy_from_slice(&digits[..8]);
+    buffer[9..13].copy_from_slice(&digits[8..12]);
+    buffer[14..18].copy_from_slice(&digits[12..16]);
+    buffer[19..23].copy_from_slice(&digits[16..20]);
+    buffer[24..].copy_from_slice(&digits[20..]);
+
+    for &position in &super::HYPHEN_POSITIONS {
+        buffer[position] = b'-';
+    }
+}
+
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+mod x86 {
+    #[cfg(target_arch = "x86")]
+    use crate::std::arch::x86::*;
+    #[cfg(target_arch = "x86_64")]
+    use crate::std::arch::x86_64::*;
+
+    /// Converts `bytes` to hex digits, returning the first and last 16.
+    #[target_feature(enable = "sse2")]
+    unsafe fn hex_sse2(bytes: &[u8; 16], upper: bool) -> (__m128i, __m128i) {
+        let bytes = _mm_loadu_si128(bytes.as_ptr() as *const __m128i);
+
+        let mask = _mm_set1_epi8(0x0f);
+        let high = _mm_and_si128(_mm_srli_epi16(bytes, 4), mask);
+        let low = _mm_and_si128(bytes, mask);
+
+        (
+            ascii_sse2(_mm_unpacklo_epi8(high, low), upper),
+            ascii_sse2(_mm_unpackhi_epi8(high, low), upper),
+        )
+    }
+
+    /// Converts each nibble in `nibbles` to its hex digit.
+    #[target_feature(enable = "sse2")]
+    unsafe fn ascii_sse2(nibbles: __m128i, upper: bool) -> __m128i {
+        // The distance from the digits to the letters.
+        let offset = if upper {
+            b'A' - b'9' - 1
+        } else {
+            b'a' - b'9' - 1
+        };
+
+        let letters = _mm_cmpgt_epi8(nibbles, _mm_set1_epi8(9));
+
+        _mm_add_epi8(
+            _mm_add_epi8(nibbles, _mm_set1_epi8(b'0' as i8)),
+            _mm_and_si128(letters, _mm_set1_epi8(offset as i8)),
+        )
+    }
+
+    #[target_feature(enable = "sse2")]
+    pub(super) unsafe fn encode_sse2(
+        digits: &mut [u8; 32],
+        bytes: &[u8; 16],
+        upper: bool,
+    ) {
+        let (first, second) = hex_sse2(bytes, upper);
+
+        _mm_storeu_si128(digits.as_mut_ptr() as *mut __m128i, first);
+        _mm_storeu_si128(digits.as_mut_ptr().add(16) as *mut __m128i, second);
+    }
+
+    #[cfg(feature = "std")]
+    #[target_feature(enable = "ssse3")]
+    pub(super) unsafe fn encode_hyphenated_ssse3(
+        buffer: &mut [u8],
+        bytes: &[u8; 16],
+        upper: bool,
+    ) {
+        // Lanes shuffled from index `Z` are zeroed, and then filled with
+        // either a hyphen or a digit from the other half.
+        const Z: i8 = -1;
+        const H: i8 = b'-' as i8;
+
+        let buffer = &mut buffer[..36];
+        let (first, second) = hex_sse2(bytes, upper);
+
+        // Digits 0..14, with hyphens at 8 and 13.
+        let start = _mm_or_si128(
+            _mm_shuffle_epi8(
+                first,
+                _mm_setr_epi8(
+                    0, 1, 2, 3, 4, 5, 6, 7, Z, 8, 9, 10, 11, Z, 12, 13,
+                ),
+            ),
+            _mm_setr_epi8(0, 0, 0, 0, 0, 0, 0, 0, H, 0, 0, 0, 0, H, 0, 0),
+        );
+
+        // Digits 14..28, with hyphens at 18 and 23.
+        let middle = _mm_or_si128(
+            _mm_or_si128(
+                _mm_shuffle_epi8(
+                    first,
+                    _mm_setr_epi8(
+                        14, 15, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z,
+                    ),
+                ),
+                _mm_shuffle_epi8(
+                    second,
+                    _mm_setr_epi8(
+                        Z, Z, Z, 0, 1, 2, 3, Z, 4, 5, 6, 7, 8, 9, 10, 11,
+                    ),
+                ),
+            ),
+            _mm_setr_epi8(0, 0, H, 0, 0, 0, 0, H, 0, 0, 0, 0, 0, 0, 0, 0),
+        );
+
+        // Digits 28..32.
+        let end = _mm_cvtsi128_si32(_mm_srli_si128(second, 12));
+
+        _mm_storeu_si128(buffer.as_mut_ptr() as *mut __m128i, start);
+        _mm_storeu_si128(buffer.as_mut_ptr().add(16) as *mut __m128i, middle);
+        buffer[32..].copy_from_slice(&end.to_le_bytes());
+    }
+}
+
+#[cfg(target_arch = "aarch64")]
+mod aarch64 {
+    use crate::std::arch::aarch64::*;
+
+    // Lanes looked up from index `Z` are zeroed, and then filled with
+    // either a hyphen or a digit from the other half.
+    const Z: u8 = 0xff;
+    const H: u8 = b'-';
+
+    const START: [u8; 16] =
+        [0, 1, 2, 3, 4, 5, 6, 7, Z, 8, 9, 10, 11, Z, 12, 13];
+    const START_HYPHENS: [u8; 16] =
+        [0, 0, 0, 0, 0, 0, 0, 0, H, 0, 0, 0, 0, H, 0, 0];
+    const MIDDLE_FIRST: [u8; 16] =
+        [14, 15, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z];
+    const MIDDLE_SECOND: [u8; 16] =
+        [Z, Z, Z, 0, 1, 2, 3, Z, 4, 5, 6, 7, 8, 9, 10, 11];
+    const MIDDLE_HYPHENS: [u8; 16] =
+        [0, 0, H, 0, 0, 0, 0, H, 0, 0, 0, 0, 0, 0, 0, 0];
+
+    #[target_feature(enable = "neon")]
+    pub(super) unsafe fn encode_neon(
+        buffer: &mut [u8],
+        bytes: &[u8; 16],
+        hyphens: bool,
+        upper: bool,
+    ) {
+        let hex = if upper {
+            &super::super::UPPER
+        } else {
+            &super::super::LOWER
+        };
+        let table = vld1q_u8(hex.as_ptr());
+
+        let bytes = vld1q_u8(bytes.as_ptr());
+        let high = vshrq_n_u8(bytes, 4);
+        let low = vandq_u8(bytes, vdupq_n_u8(0x0f));
+
+        let first = vqtbl1q_u8(table, vzip1q_u8(high, low));
+        let second = vqtbl1q_u8(table, vzip2q_u8(high, low));
+
+        if !hyphens {
+            let buffer = &mut buffer[..32];
+
+            vst1q_u8(buffer.as_mut_ptr(), first);
+            vst1q_u8(buffer.as_mut_ptr().add(16), second);
+
+            return;
+        }
+
+        let buffer = &mut buffer[..36];
+
+        // Digits 0..14, with hyphens at 8 and 13.
+        let start = vorrq_u8(
+            vqtbl1q_u8(first, vld1q_u8(START.as_ptr())),
+            vld1q_u8(START_HYPHENS.as_ptr()),
+        );
+
+        // Digits 14..28, with hyphens at 18 and 23.
+        let middle = vorrq_u8(
+            vorrq_u8(
+                vqtbl1q_u8(first, vld1q_u8(MIDDLE_FIRST.as_ptr())),
+                vqtbl1q_u8(second, vld1q_u8(MIDDLE_SECOND.as_ptr())),
+            ),
+            vld1q_u8(MIDDLE_HYPHENS.as_ptr()),
+        );
+
+        // Digits 28..32.
+        let end = vgetq_lane_u32(vreinterpretq_u32_u8(second), 3);
+
+        vst1q_u8(buffer.as_mut_ptr(), start);
+        vst1q_u8(buffer.as_mut_ptr().add(16), middle);
+        buffer[32..].copy_from_slice(&end.to_le_bytes());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Bytes that cover every nibble in every position.
+    fn samples() -> impl Iterator<Item = [u8; 16]> {
+        (0..=255u8).map(|seed| {
+            let mut bytes = [0u8; 16];
+            for (i, byte) in bytes.iter_mut().enumerate() {
+                *byte = seed.wrapping_add((i as u8).wrapping_mul(17));
+            }
+            bytes
+        })
+    }
+
+    #[test]
+    fn test_encode_matches_scalar() {
+        for bytes in samples() {
+            for &hyphens in &[false, true] {
+                for &upper in &[false, true] {
+                    let len = if hyphens { 36 } else { 32 };
+
+                    let mut expected = [0u8; 36];
+                    super::super::encode_scalar(
+                        &mut expected[..len],
+                        &bytes,
+                        hyphens,
+                        upper,
+                    );
+
+                    let mut encoded = [b'!'; 40];
+                    if encode(&mut encoded, &bytes, hyphens, upper) {
+                        assert_eq!(&encoded[..len], &expected[..len]);
+                        assert!(encoded[len..].iter().all(|b| *b == b'!'));
+                    }
+                }
+            }
+        }
+    }
+
+    #[cfg(all(feature = "std", target_arch = "x86_64"))]
+    #[test]
+    fn test_encode_hyphenated_sse2_matches_ssse3() {
+        if !is_x86_feature_detected!("ssse3") {
+            return;
+        }
+
+        for bytes in samples() {
+            for &upper in &[false, true] {
+                let mut digits = [0u8; 32];
+                let mut expected = [0u8; 36];
+                let mut encoded = [0u8; 36];
+
+                // SAFETY: SSE2 is part of x86_64 and SSSE3 was detected.
+                unsafe {
+                    x86::encode_sse2(&mut digits, &bytes, upper);
+                    x86::encode_hyphenated_ssse3(&mut encoded, &bytes, upper);
+                }
+                hyphenate(&mut expected, &digits);
+
+                assert_eq!(&encoded[..], &expected[..]);
+            }
+        }
+    }
+}
@@ -0,0 +1,190 @@
+// Copyright 2013-2014 The Rust Project Developers.
+// Copyright 2018 The Uuid Project Developers.
+//
+// See the COPYRIGHT file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Encoding adapters to UTF-16.
+
+use super::{
+    Braced, BracedRef, Hyphenated, HyphenatedRef, Simple, SimpleRef, Urn,
+    UrnRef,
+};
+use crate::Uuid;
+
+impl Uuid {
+    /// A buffer that can be used for `encode_..._utf16` calls, that is
+    /// guaranteed to be long enough for any of the adapters.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use uuid::Uuid;
+    ///
+    /// let uuid = Uuid::nil();
+    /// let expected: Vec<u16> =
+    ///     "00000000-0000-0000-0000-000000000000".encode_utf16().collect();
+    ///
+    /// assert_eq!(
+    ///     uuid.to_hyphenated()
+    ///         .encode_lower_utf16(&mut Uuid::encode_buffer_utf16()),
+    ///     &expected[..]
+    /// );
+    /// ```
+    pub const fn encode_buffer_utf16() -> [u16; Urn::LENGTH] {
+        [0; Urn::LENGTH]
+    }
+}
+
+/// Widens the ASCII `encoded` string into `buffer`, and returns the subslice
+/// of the buffer that contains it.
+fn encode_utf16<'buf>(
+    buffer: &'buf mut [u16],
+    encoded: &str,
+) -> &'buf mut [u16] {
+    let buffer = &mut buffer[..encoded.len()];
+
+    for (unit, byte) in buffer.iter_mut().zip(encoded.bytes()) {
+        *unit = u16::from(byte);
+    }
+
+    buffer
+}
+
+macro_rules! impl_encode_utf16 {
+    ($($T:ident<$($a:lifetime),*>),+) => {$(
+        impl<$($a),*> $T<$($a),*> {
+            /// Writes the [`Uuid`] in this format as lower-case UTF-16 to
+            /// `buffer`, and returns the subslice of the buffer that contains
+            /// the encoded UUID.
+            ///
+            /// This produces the same characters as `encode_lower`, one
+            /// UTF-16 code unit per character.
+            ///
+            /// [`Uuid`]: ../struct.Uuid.html
+            ///
+            /// # Panics
+            ///
+            /// Panics if the buffer is not large enough: it must have length
+            /// at least [`LENGTH`]. [`Uuid::encode_buffer_utf16`] can be used
+            /// to get a sufficiently-large temporary buffer.
+            ///
+            /// [`LENGTH`]: #associatedconstant.LENGTH
+            /// [`Uuid::encode_buffer_utf16`]: ../struct.Uuid.html#method.encode_buffer_utf16
+            pub fn encode_lower_utf16<'buf>(
+                &self,
+                buffer: &'buf mut [u16],
+            ) -> &'buf mut [u16] {
+                encode_utf16(buffer, self.encode_lower(&mut [0; $T::LENGTH]))
+            }
+
+            /// Writes the [`Uuid`] in this format as upper-case UTF-16 to
+            /// `buffer`, and returns the subslice of the buffer that contains
+            /// the encoded UUID.
+            ///
+            /// This produces the same characters as `encode_upper`, one
+            /// UTF-16 code unit per character.
+            ///
+            /// [`Uuid`]: ../struct.Uuid.html
+            ///
+            /// # Panics
+            ///
+            /// Panics if the buffer is not large enough: it must have length
+            /// at least [`LENGTH`]. [`Uuid::encode_buffer_utf16`] can be used
+            /// to get a sufficiently-large temporary buffer.
+            ///
+            /// [`LENGTH`]: #associatedconstant.LENGTH
+            /// [`Uuid::encode_buffer_utf16`]: ../struct.Uuid.html#method.encode_buffer_utf16
+            pub fn encode_upper_utf16<'buf>(
+                &self,
+                buffer: &'buf mut [u16],
+            ) -> &'buf mut [u16] {
+                encode_utf16(buffer, self.encode_upper(&mut [0; $T::LENGTH]))
+            }
+        }
+    )+}
+}
+
+impl_encode_utf16! {
+    Hyphenated<>,
+    HyphenatedRef<'a>,
+    Simple<>,
+    SimpleRef<'a>,
+    Urn<>,
+    UrnRef<'a>,
+    Braced<>,
+    BracedRef<'a>
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use crate::std::{string::ToString, vec::Vec};
+
+    fn utf16(s: &str) -> Vec<u16> {
+        s.encode_utf16().collect()
+    }
+
+    #[test]
+    fn test_encode_utf16() {
+        let uuid =
+            Uuid::parse_str("67e55044-10b1-426f-9247-bb680e5fe0c8").unwrap();
+        let mut buffer = Uuid::encode_buffer_utf16();
+
+        assert_eq!(
+            uuid.to_hyphenated().encode_lower_utf16(&mut buffer),
+            &utf16(&uuid.to_hyphenated().to_string())[..]
+        );
+        assert_eq!(
+            uuid.to_hyphenated_ref().encode_upper_utf16(&mut buffer),
+            &utf16(&format!("{:X}", uuid.to_hyphenated_ref()))[..]
+        );
+        assert_eq!(
+            uuid.to_simple().encode_lower_utf16(&mut buffer),
+            &utf16(&uuid.to_simple().to_string())[..]
+        );
+        assert_eq!(
+            uuid.to_simple_ref().encode_upper_utf16(&mut buffer),
+            &utf16(&format!("{:X}", uuid.to_simple_ref()))[..]
+        );
+        assert_eq!(
+            uuid.to_urn().encode_lower_utf16(&mut buffer),
+            &utf16(&uuid.to_urn().to_string())[..]
+        );
+        assert_eq!(
+            uuid.to_urn_ref().encode_upper_utf16(&mut buffer),
+            &utf16(&format!("{:X}", uuid.to_urn_ref()))[..]
+        );
+        assert_eq!(
+            uuid.to_braced().encode_lower_utf16(&mut buffer),
+            &utf16(&uuid.to_braced().to_string())[..]
+        );
+        assert_eq!(
+            uuid.to_braced_ref().encode_upper_utf16(&mut buffer),
+            &utf16(&format!("{:X}", uuid.to_braced_ref()))[..]
+        );
+    }
+
+    #[test]
+    fn test_encode_utf16_trailing() {
+        let mut buffer = [0x21; 50];
+        let len = Uuid::nil()
+            .to_braced()
+            .encode_lower_utf16(&mut buffer)
+            .len();
+
+        assert_eq!(len, super::Braced::LENGTH);
+        assert!(buffer[len..].iter().all(|unit| *unit == 0x21));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_encode_utf16_too_small() {
+        Uuid::nil().to_simple().encode_lower_utf16(&mut [0; 31]);
+    }
+}
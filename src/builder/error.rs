@@ -5,8 +5,8 @@ use crate::std::fmt;
 /// [`Uuid`]: struct.Uuid.html
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
 pub(crate) struct Error {
-    expected: usize,
-    found: usize,
+    pub(crate) expected: usize,
+    pub(crate) found: usize,
 }
 
 impl Error {
@@ -0,0 +1,715 @@
+// Copyright 2013-2014 The Rust Project Developers.
+// Copyright 2018 The Uuid Project Developers.
+//
+// See the COPYRIGHT file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! A canonical binary encoding of `serde` values, used as the name of
+//! name-based UUIDs.
+
+use crate::prelude::*;
+use crate::std::{
+    fmt,
+    string::{String, ToString},
+    vec::Vec,
+};
+use serde::{ser, Serialize};
+
+impl Uuid {
+    /// Creates a UUID using a `serde` serializable value from a namespace,
+    /// based on the SHA-1 hash.
+    ///
+    /// This is [`new_v5`] with the name being a canonical encoding of
+    /// `value`, so equal values produce equal UUIDs in every process,
+    /// regardless of things like the iteration order of a `HashMap`.
+    ///
+    /// The encoding follows the `serde` data model, where every value is
+    /// written as a one byte tag followed by its contents:
+    ///
+    /// * `0x00`: unit and unit structs.
+    /// * `0x01`: booleans, followed by `0x00` for `false` or `0x01` for
+    ///   `true`.
+    /// * `0x02`: integers of any width, followed by `0x00` if the integer is
+    ///   non-negative or `0x01` if it's negative, and then its magnitude as
+    ///   a 16 byte big-endian number. Equal integers of different types
+    ///   encode the same.
+    /// * `0x03`: floating point numbers, followed by the IEEE 754 bits of the
+    ///   number widened to `f64` as an 8 byte big-endian number. `-0.0` is
+    ///   written as `0.0`, and all NaNs are written as the same quiet NaN.
+    /// * `0x04`: strings and characters, followed by the length of their
+    ///   UTF-8 encoding as an 8 byte big-endian number and then the UTF-8
+    ///   bytes.
+    /// * `0x05`: byte arrays, followed by their length as an 8 byte
+    ///   big-endian number and then the bytes.
+    /// * `0x06`: sequences, tuples and tuple structs, followed by the number
+    ///   of elements as an 8 byte big-endian number and then each element.
+    /// * `0x07`: maps and structs, followed by the number of entries as an 8
+    ///   byte big-endian number and then each key and value. The entries are
+    ///   sorted by the bytes of their encoded keys. Struct fields are keyed
+    ///   by their names as strings, so a struct encodes the same as a map
+    ///   with the same entries.
+    /// * `0x08`: `None`.
+    /// * `0x09`: `Some`, followed by its inner value.
+    /// * `0x0a`: enum variants, followed by the name of the variant as a
+    ///   string and then its contents: a unit for unit variants, the inner
+    ///   value for newtype variants, a sequence for tuple variants and a map
+    ///   for struct variants.
+    ///
+    /// Newtype structs and newtype wrappers encode as their inner value.
+    ///
+    /// Note that usage of this method requires the `v5`, `serde` and `std`
+    /// features of this crate to be enabled.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `value` fails to serialize itself.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::HashMap;
+    /// use uuid::Uuid;
+    ///
+    /// fn main() -> Result<(), uuid::Error> {
+    ///     let mut a = HashMap::new();
+    ///     a.insert("region", "eu-west-1");
+    ///     a.insert("bucket", "assets");
+    ///
+    ///     let mut b = HashMap::new();
+    ///     b.insert("bucket", "assets");
+    ///     b.insert("region", "eu-west-1");
+    ///
+    ///     assert_eq!(
+    ///         Uuid::new_v5_from_value(&Uuid::NAMESPACE_URL, &a)?,
+    ///         Uuid::new_v5_from_value(&Uuid::NAMESPACE_URL, &b)?,
+    ///     );
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    /// [`new_v5`]: #method.new_v5
+    pub fn new_v5_from_value<T: Serialize + ?Sized>(
+        namespace: &Uuid,
+        value: &T,
+    ) -> Result<Uuid, crate::Error> {
+        let mut name = Vec::new();
+        value.serialize(Serializer { out: &mut name })?;
+
+        Ok(Uuid::new_v5(namespace, &name))
+    }
+}
+
+const UNIT: u8 = 0x00;
+const BOOL: u8 = 0x01;
+const INT: u8 = 0x02;
+const FLOAT: u8 = 0x03;
+const STR: u8 = 0x04;
+const BYTES: u8 = 0x05;
+const SEQ: u8 = 0x06;
+const MAP: u8 = 0x07;
+const NONE: u8 = 0x08;
+const SOME: u8 = 0x09;
+const VARIANT: u8 = 0x0a;
+
+/// The error that can occur when a value fails to serialize itself.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub(crate) struct Error(String);
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "failed to serialize value: {}", self.0)
+    }
+}
+
+impl crate::std::error::Error for Error {}
+
+impl ser::Error for Error {
+    fn custom<T: fmt::Display>(msg: T) -> Self {
+        Error(msg.to_string())
+    }
+}
+
+fn write_header(out: &mut Vec<u8>, tag: u8, len: usize) {
+    out.push(tag);
+    out.extend_from_slice(&(len as u64).to_be_bytes());
+}
+
+fn write_int(out: &mut Vec<u8>, negative: bool, magnitude: u128) {
+    out.push(INT);
+    out.push(negative as u8);
+    out.extend_from_slice(&magnitude.to_be_bytes());
+}
+
+fn write_str(out: &mut Vec<u8>, v: &str) {
+    write_header(out, STR, v.len());
+    out.extend_from_slice(v.as_bytes());
+}
+
+/// Writes the tag and name of a variant, leaving its contents to be written
+/// by the caller.
+fn write_variant_header(out: &mut Vec<u8>, variant: &str) {
+    out.push(VARIANT);
+    write_str(out, variant);
+}
+
+struct Serializer<'a> {
+    out: &'a mut Vec<u8>,
+}
+
+struct Seq<'a> {
+    out: &'a mut Vec<u8>,
+    len: usize,
+    elements: Vec<u8>,
+}
+
+struct Map<'a> {
+    out: &'a mut Vec<u8>,
+    entries: Vec<(Vec<u8>, Vec<u8>)>,
+    key: Vec<u8>,
+}
+
+impl<'a> Seq<'a> {
+    fn new(out: &'a mut Vec<u8>) -> Self {
+        Seq {
+            out,
+            len: 0,
+            elements: Vec::new(),
+        }
+    }
+
+    fn element<T: Serialize + ?Sized>(&mut self, v: &T) -> Result<(), Error> {
+        v.serialize(Serializer {
+            out: &mut self.elements,
+        })?;
+        self.len += 1;
+
+        Ok(())
+    }
+
+    fn finish(self) -> Result<(), Error> {
+        write_header(self.out, SEQ, self.len);
+        self.out.extend_from_slice(&self.elements);
+
+        Ok(())
+    }
+}
+
+impl<'a> Map<'a> {
+    fn new(out: &'a mut Vec<u8>) -> Self {
+        Map {
+            out,
+            entries: Vec::new(),
+            key: Vec::new(),
+        }
+    }
+
+    fn key<T: Serialize + ?Sized>(&mut self, k: &T) -> Result<(), Error> {
+        self.key.clear();
+        k.serialize(Serializer { out: &mut self.key })
+    }
+
+    fn value<T: Serialize + ?Sized>(&mut self, v: &T) -> Result<(), Error> {
+        let mut value = Vec::new();
+        v.serialize(Serializer { out: &mut value })?;
+
+        let key = crate::std::mem::take(&mut self.key);
+        self.entries.push((key, value));
+
+        Ok(())
+    }
+
+    fn field<T: Serialize + ?Sized>(
+        &mut self,
+        name: &'static str,
+        v: &T,
+    ) -> Result<(), Error> {
+        self.key(name)?;
+        self.value(v)
+    }
+
+    fn finish(mut self) -> Result<(), Error> {
+        self.entries.sort();
+
+        write_header(self.out, MAP, self.entries.len());
+        for (key, value) in &self.entries {
+            self.out.extend_from_slice(key);
+            self.out.extend_from_slice(value);
+        }
+
+        Ok(())
+    }
+}
+
+impl<'a> ser::Serializer for Serializer<'a> {
+    type Ok = ();
+    type Error = Error;
+
+    type SerializeSeq = Seq<'a>;
+    type SerializeTuple = Seq<'a>;
+    type SerializeTupleStruct = Seq<'a>;
+    type SerializeTupleVariant = Seq<'a>;
+    type SerializeMap = Map<'a>;
+    type SerializeStruct = Map<'a>;
+    type SerializeStructVariant = Map<'a>;
+
+    fn serialize_bool(self, v: bool) -> Result<(), Error> {
+        self.out.push(BOOL);
+        self.out.push(v as u8);
+
+        Ok(())
+    }
+
+    fn serialize_i8(self, v: i8) -> Result<(), Error> {
+        self.serialize_i128(v.into())
+    }
+
+    fn serialize_i16(self, v: i16) -> Result<(), Error> {
+        self.serialize_i128(v.into())
+    }
+
+    fn serialize_i32(self, v: i32) -> Result<(), Error> {
+        self.serialize_i128(v.into())
+    }
+
+    fn serialize_i64(self, v: i64) -> Result<(), Error> {
+        self.serialize_i128(v.into())
+    }
+
+    fn serialize_i128(self, v: i128) -> Result<(), Error> {
+        write_int(self.out, v < 0, v.unsigned_abs());
+
+        Ok(())
+    }
+
+    fn serialize_u8(self, v: u8) -> Result<(), Error> {
+        self.serialize_u128(v.into())
+    }
+
+    fn serialize_u16(self, v: u16) -> Result<(), Error> {
+        self.serialize_u128(v.into())
+    }
+
+    fn serialize_u32(self, v: u32) -> Result<(), Error> {
+        self.serialize_u128(v.into())
+    }
+
+    fn serialize_u64(self, v: u64) -> Result<(), Error> {
+        self.serialize_u128(v.into())
+    }
+
+    fn serialize_u128(self, v: u128) -> Result<(), Error> {
+        write_int(self.out, false, v);
+
+        Ok(())
+    }
+
+    fn serialize_f32(self, v: f32) -> Result<(), Error> {
+        self.serialize_f64(v.into())
+    }
+
+    fn serialize_f64(self, v: f64) -> Result<(), Error> {
+        let v = if v == 0.0 {
+            0.0
+        } else if v.is_nan() {
+            f64::NAN
+        } else {
+            v
+        };
+
+        self.out.push(FLOAT);
+        self.out.extend_from_slice(&v.to_bits().to_be_bytes());
+
+        Ok(())
+    }
+
+    fn serialize_char(self, v: char) -> Result<(), Error> {
+        self.serialize_str(v.encode_utf8(&mut [0; 4]))
+    }
+
+    fn serialize_str(self, v: &str) -> Result<(), Error> {
+        write_str(self.out, v);
+
+        Ok(())
+    }
+
+    fn serialize_bytes(self, v: &[u8]) -> Result<(), Error> {
+        write_header(self.out, BYTES, v.len());
+        self.out.extend_from_slice(v);
+
+        Ok(())
+    }
+
+    fn serialize_none(self) -> Result<(), Error> {
+        self.out.push(NONE);
+
+        Ok(())
+    }
+
+    fn serialize_some<T: Serialize + ?Sized>(
+        self,
+        value: &T,
+    ) -> Result<(), Error> {
+        self.out.push(SOME);
+        value.serialize(self)
+    }
+
+    fn serialize_unit(self) -> Result<(), Error> {
+        self.out.push(UNIT);
+
+        Ok(())
+    }
+
+    fn serialize_unit_struct(self, _: &'static str) -> Result<(), Error> {
+        self.serialize_unit()
+    }
+
+    fn serialize_unit_variant(
+        self,
+        _: &'static str,
+        _: u32,
+        variant: &'static str,
+    ) -> Result<(), Error> {
+        write_variant_header(self.out, variant);
+        self.serialize_unit()
+    }
+
+    fn serialize_newtype_struct<T: Serialize + ?Sized>(
+        self,
+        _: &'static str,
+        value: &T,
+    ) -> Result<(), Error> {
+        value.serialize(self)
+    }
+
+    fn serialize_newtype_variant<T: Serialize + ?Sized>(
+        self,
+        _: &'static str,
+        _: u32,
+        variant: &'static str,
+        value: &T,
+    ) -> Result<(), Error> {
+        write_variant_header(self.out, variant);
+        value.serialize(self)
+    }
+
+    fn collect_str<T: fmt::Display + ?Sized>(
+        self,
+        value: &T,
+    ) -> Result<(), Error> {
+        self.serialize_str(&value.to_string())
+    }
+
+    fn serialize_seq(self, _: Option<usize>) -> Result<Seq<'a>, Error> {
+        Ok(Seq::new(self.out))
+    }
+
+    fn serialize_tuple(self, _: usize) -> Result<Seq<'a>, Error> {
+        Ok(Seq::new(self.out))
+    }
+
+    fn serialize_tuple_struct(
+        self,
+        _: &'static str,
+        _: usize,
+    ) -> Result<Seq<'a>, Error> {
+        Ok(Seq::new(self.out))
+    }
+
+    fn serialize_tuple_variant(
+        self,
+        _: &'static str,
+        _: u32,
+        variant: &'static str,
+        _: usize,
+    ) -> Result<Seq<'a>, Error> {
+        write_variant_header(self.out, variant);
+        Ok(Seq::new(self.out))
+    }
+
+    fn serialize_map(self, _: Option<usize>) -> Result<Map<'a>, Error> {
+        Ok(Map::new(self.out))
+    }
+
+    fn serialize_struct(
+        self,
+        _: &'static str,
+        _: usize,
+    ) -> Result<Map<'a>, Error> {
+        Ok(Map::new(self.out))
+    }
+
+    fn serialize_struct_variant(
+        self,
+        _: &'static str,
+        _: u32,
+        variant: &'static str,
+        _: usize,
+    ) -> Result<Map<'a>, Error> {
+        write_variant_header(self.out, variant);
+        Ok(Map::new(self.out))
+    }
+}
+
+macro_rules! impl_serialize_seq {
+    ($($T:ident::$method:ident),+) => {$(
+        impl<'a> ser::$T for Seq<'a> {
+            type Ok = ();
+            type Error = Error;
+
+            fn $method<T: Serialize + ?Sized>(
+                &mut self,
+                value: &T,
+            ) -> Result<(), Error> {
+                self.element(value)
+            }
+
+            fn end(self) -> Result<(), Error> {
+                self.finish()
+            }
+        }
+    )+}
+}
+
+impl_serialize_seq! {
+    SerializeSeq::serialize_element,
+    SerializeTuple::serialize_element,
+    SerializeTupleStruct::serialize_field,
+    SerializeTupleVariant::serialize_field
+}
+
+impl<'a> ser::SerializeMap for Map<'a> {
+    type Ok = ();
+    type Error = Error;
+
+    fn serialize_key<T: Serialize + ?Sized>(
+        &mut self,
+        key: &T,
+    ) -> Result<(), Error> {
+        self.key(key)
+    }
+
+    fn serialize_value<T: Serialize + ?Sized>(
+        &mut self,
+        value: &T,
+    ) -> Result<(), Error> {
+        self.value(value)
+    }
+
+    fn end(self) -> Result<(), Error> {
+        self.finish()
+    }
+}
+
+impl<'a> ser::SerializeStruct for Map<'a> {
+    type Ok = ();
+    type Error = Error;
+
+    fn serialize_field<T: Serialize + ?Sized>(
+        &mut self,
+        name: &'static str,
+        value: &T,
+    ) -> Result<(), Error> {
+        self.field(name, value)
+    }
+
+    fn end(self) -> Result<(), Error> {
+        self.finish()
+    }
+}
+
+impl<'a> ser::SerializeStructVariant for Map<'a> {
+    type Ok = ();
+    type Error = Error;
+
+    fn serialize_field<T: Serialize + ?Sized>(
+        &mut self,
+        name: &'static str,
+        value: &T,
+    ) -> Result<(), Error> {
+        self.field(name, value)
+    }
+
+    fn end(self) -> Result<(), Error> {
+        self.finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::std::collections::{BTreeMap, HashMap};
+    use serde_derive::Serialize;
+
+    fn encode<T: Serialize + ?Sized>(value: &T) -> Vec<u8> {
+        let mut out = Vec::new();
+        value.serialize(Serializer { out: &mut out }).unwrap();
+        out
+    }
+
+    #[test]
+    fn test_encode_scalars() {
+        assert_eq!(encode(&()), [UNIT]);
+        assert_eq!(encode(&true), [BOOL, 1]);
+
+        let mut five = vec![INT, 0];
+        five.extend_from_slice(&5u128.to_be_bytes());
+        assert_eq!(encode(&5u8), five);
+        assert_eq!(encode(&5i64), five);
+
+        let mut minus_five = vec![INT, 1];
+        minus_five.extend_from_slice(&5u128.to_be_bytes());
+        assert_eq!(encode(&-5i32), minus_five);
+
+        assert_eq!(encode(&-0.0f64), encode(&0.0f32));
+        assert_eq!(encode(&f64::NAN), encode(&-f32::NAN));
+        assert_ne!(encode(&1.0f64), encode(&1u8));
+
+        assert_eq!(encode("ab"), [STR, 0, 0, 0, 0, 0, 0, 0, 2, b'a', b'b'],);
+        assert_eq!(encode(&'a'), encode("a"));
+        assert_eq!(encode(&None::<u8>), [NONE]);
+
+        let mut some_five = vec![SOME];
+        some_five.extend(encode(&5u8));
+        assert_eq!(encode(&Some(5u8)), some_five);
+        assert_ne!(encode(&None::<()>), encode(&Some(())));
+        assert_ne!(encode(&None::<()>), encode(&()));
+    }
+
+    #[test]
+    fn test_encode_collect_str() {
+        struct Display;
+
+        impl fmt::Display for Display {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                write!(f, "a-{}", 1)
+            }
+        }
+
+        impl Serialize for Display {
+            fn serialize<S: ser::Serializer>(
+                &self,
+                serializer: S,
+            ) -> Result<S::Ok, S::Error> {
+                serializer.collect_str(self)
+            }
+        }
+
+        assert_eq!(encode(&Display), encode("a-1"));
+    }
+
+    #[test]
+    fn test_encode_maps_sorted() {
+        let mut a = HashMap::new();
+        let mut b = BTreeMap::new();
+        for i in 0..32u32 {
+            a.insert(i, i * 2);
+            b.insert(i, i * 2);
+        }
+
+        assert_eq!(encode(&a), encode(&b));
+    }
+
+    #[test]
+    fn test_encode_struct_as_map() {
+        #[derive(Serialize)]
+        struct Request {
+            per_page: u32,
+            page: u32,
+        }
+
+        let request = Request {
+            per_page: 10,
+            page: 2,
+        };
+
+        let mut expected = Vec::new();
+        write_header(&mut expected, MAP, 2);
+        expected.extend(encode("page"));
+        expected.extend(encode(&2u8));
+        expected.extend(encode("per_page"));
+        expected.extend(encode(&10u8));
+
+        assert_eq!(encode(&request), expected);
+
+        let mut map = BTreeMap::new();
+        map.insert("per_page", 10);
+        map.insert("page", 2);
+
+        assert_eq!(encode(&request), encode(&map));
+    }
+
+    #[test]
+    fn test_encode_variants() {
+        #[derive(Serialize)]
+        enum Shape {
+            Empty,
+            Circle(u8),
+            Line(u8, u8),
+            Rect { w: u8, h: u8 },
+        }
+
+        fn variant<T: Serialize>(name: &str, contents: T) -> Vec<u8> {
+            let mut out = vec![VARIANT];
+            out.extend(encode(name));
+            out.extend(encode(&contents));
+            out
+        }
+
+        assert_eq!(encode(&Shape::Empty), variant("Empty", ()));
+        assert_ne!(encode(&Shape::Empty), encode("Empty"));
+
+        assert_eq!(encode(&Shape::Circle(1)), variant("Circle", 1));
+
+        let mut circle = BTreeMap::new();
+        circle.insert("Circle", 1);
+        assert_ne!(encode(&Shape::Circle(1)), encode(&circle));
+
+        assert_eq!(encode(&Shape::Line(1, 2)), variant("Line", (1, 2)));
+
+        let mut fields = BTreeMap::new();
+        fields.insert("w", 1);
+        fields.insert("h", 2);
+        assert_eq!(
+            encode(&Shape::Rect { w: 1, h: 2 }),
+            variant("Rect", fields)
+        );
+    }
+
+    #[test]
+    fn test_new_v5_from_value() {
+        let mut name = Vec::new();
+        write_str(&mut name, "rust-lang.org");
+
+        assert_eq!(
+            Uuid::new_v5_from_value(&Uuid::NAMESPACE_DNS, "rust-lang.org")
+                .unwrap(),
+            Uuid::new_v5(&Uuid::NAMESPACE_DNS, &name)
+        );
+    }
+
+    #[test]
+    fn test_new_v5_from_value_error() {
+        struct Failing;
+
+        impl Serialize for Failing {
+            fn serialize<S: ser::Serializer>(
+                &self,
+                _: S,
+            ) -> Result<S::Ok, S::Error> {
+                Err(ser::Error::custom("nope"))
+            }
+        }
+
+        let err = Uuid::new_v5_from_value(&Uuid::NAMESPACE_DNS, &[Failing])
+            .unwrap_err();
+
+        assert_eq!(err.to_string(), "failed to serialize value: nope");
+    }
+}
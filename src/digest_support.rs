@@ -0,0 +1,159 @@
+// Copyright 2013-2014 The Rust Project Developers.
+// Copyright 2018 The Uuid Project Developers.
+//
+// See the COPYRIGHT file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use crate::prelude::*;
+use digest::{
+    consts::U16,
+    typenum::{IsGreaterOrEqual, True},
+    Digest,
+};
+
+impl Uuid {
+    /// Creates a UUID using a name from a namespace, based on the hash
+    /// computed by any [`Digest`] implementation.
+    ///
+    /// The namespace and name are hashed together, the hash is truncated to
+    /// 128 bits, and then the given `version` and the RFC4122 variant are
+    /// set. This is how [`new_v3`] and [`new_v5`] are specified, so passing
+    /// the matching hash and version reproduces their output.
+    ///
+    /// Note that usage of this method requires the `digest` feature of this
+    /// crate to be enabled.
+    ///
+    /// Digests with less than 16 bytes of output can't fill a UUID, so
+    /// they're rejected at compile time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[cfg(feature = "v8")]
+    /// # {
+    /// use uuid::{Uuid, Version};
+    ///
+    /// let uuid = Uuid::new_name_based::<sha2::Sha256>(
+    ///     Version::Custom,
+    ///     &Uuid::NAMESPACE_DNS,
+    ///     b"www.example.com",
+    /// );
+    ///
+    /// assert_eq!(
+    ///     uuid.to_hyphenated().to_string(),
+    ///     "5c146b14-3c52-8afd-938a-375d0df1fbf6"
+    /// );
+    /// # }
+    /// ```
+    ///
+    /// A digest with a 64-bit output doesn't compile:
+    ///
+    /// ```compile_fail
+    /// use digest::{
+    ///     consts::U8, FixedOutput, HashMarker, Output, OutputSizeUser, Update,
+    /// };
+    /// use uuid::{Uuid, Version};
+    ///
+    /// #[derive(Default)]
+    /// struct Short;
+    ///
+    /// impl HashMarker for Short {}
+    ///
+    /// impl OutputSizeUser for Short {
+    ///     type OutputSize = U8;
+    /// }
+    ///
+    /// impl Update for Short {
+    ///     fn update(&mut self, _: &[u8]) {}
+    /// }
+    ///
+    /// impl FixedOutput for Short {
+    ///     fn finalize_into(self, _: &mut Output<Self>) {}
+    /// }
+    ///
+    /// Uuid::new_name_based::<Short>(Version::Custom, &Uuid::nil(), b"");
+    /// ```
+    ///
+    /// [`Digest`]: https://docs.rs/digest/0.10/digest/trait.Digest.html
+    /// [`new_v3`]: #method.new_v3
+    /// [`new_v5`]: #method.new_v5
+    pub fn new_name_based<D>(
+        version: Version,
+        namespace: &Uuid,
+        name: &[u8],
+    ) -> Uuid
+    where
+        D: Digest,
+        D::OutputSize: IsGreaterOrEqual<U16, Output = True>,
+    {
+        let mut hash = D::new();
+
+        hash.update(namespace.as_bytes());
+        hash.update(name);
+
+        let buffer = hash.finalize();
+
+        let mut bytes = crate::Bytes::default();
+        bytes.copy_from_slice(&buffer[..16]);
+
+        crate::Builder::from_bytes(bytes)
+            .set_variant(Variant::RFC4122)
+            .set_version(version)
+            .build()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    #[test]
+    #[cfg(feature = "v8")]
+    fn test_matches_v8_sha256() {
+        let uuid = Uuid::new_name_based::<sha2::Sha256>(
+            Version::Custom,
+            &Uuid::NAMESPACE_URL,
+            b"rust-lang.org",
+        );
+
+        assert_eq!(
+            uuid,
+            Uuid::new_v8_sha256(&Uuid::NAMESPACE_URL, b"rust-lang.org")
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "v5-digest")]
+    fn test_matches_v5() {
+        let uuid = Uuid::new_name_based::<sha1_digest::Sha1>(
+            Version::Sha1,
+            &Uuid::NAMESPACE_DNS,
+            b"rust-lang.org",
+        );
+
+        assert_eq!(
+            uuid,
+            Uuid::parse_str("c66bbb60-d62e-5f17-a399-3a0bd237c503").unwrap()
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "v3-digest")]
+    fn test_matches_v3() {
+        let uuid = Uuid::new_name_based::<md5_digest::Md5>(
+            Version::Md5,
+            &Uuid::NAMESPACE_DNS,
+            b"rust-lang.org",
+        );
+
+        assert_eq!(
+            uuid,
+            Uuid::parse_str("c6db027c-615c-3b4d-959e-1a917747ca5a").unwrap()
+        );
+    }
+}
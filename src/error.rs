@@ -1,4 +1,4 @@
-use crate::std::fmt;
+use crate::std::{fmt, slice};
 use crate::{builder, parser};
 
 /// A general error that can occur when working with UUIDs.
@@ -7,8 +7,60 @@ use crate::{builder, parser};
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
 pub struct Error(Inner);
 
-// TODO: write tests for Error
-// BODY: not immediately blocking, but should be covered for 1.0
+/// The kind of an [`Error`].
+///
+/// More kinds may be added in the future, so matches on this enum should
+/// include a wildcard arm.
+///
+/// # Examples
+///
+/// ```
+/// use uuid::{ErrorKind, Uuid};
+///
+/// let err = Uuid::parse_str("67e55044-10b1-426f-9247-bb680e5fe0cX")
+///     .unwrap_err();
+///
+/// assert_eq!(err.kind(), ErrorKind::InvalidCharacter);
+/// assert_eq!(err.character(), Some('X'));
+/// assert_eq!(err.index(), Some(35));
+/// ```
+///
+/// [`Error`]: struct.Error.html
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[non_exhaustive]
+pub enum ErrorKind {
+    /// A [`Uuid`] string contains a character that isn't valid at its
+    /// position.
+    ///
+    /// [`Uuid`]: struct.Uuid.html
+    InvalidCharacter,
+    /// A [`Uuid`] string has the wrong number of hyphen-separated groups.
+    ///
+    /// [`Uuid`]: struct.Uuid.html
+    InvalidGroupCount,
+    /// A group in a [`Uuid`] string has the wrong number of digits.
+    ///
+    /// [`Uuid`]: struct.Uuid.html
+    InvalidGroupLength,
+    /// A [`Uuid`] string has the wrong length.
+    ///
+    /// [`Uuid`]: struct.Uuid.html
+    InvalidLength,
+    /// A string ended before all of a [`Uuid`] was read, such as a
+    /// truncated C `GUID` initializer.
+    ///
+    /// [`Uuid`]: struct.Uuid.html
+    UnexpectedEnd,
+    /// A slice of bytes has the wrong length.
+    InvalidBytesLength,
+    /// A name couldn't be normalized in one of the predefined namespaces.
+    InvalidName,
+    /// A value couldn't be serialized to hash it into a [`Uuid`].
+    ///
+    /// [`Uuid`]: struct.Uuid.html
+    Serialization,
+}
+
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
 enum Inner {
     /// An error occurred while handling [`Uuid`] bytes.
@@ -26,6 +78,188 @@ enum Inner {
     /// [`parser::ParseError`]: parser/enum.ParseError.html
     /// [`Uuid`]: struct.Uuid.html
     Parser(parser::Error),
+
+    /// An error occurred while normalizing a name in one of the predefined
+    /// namespaces.
+    #[cfg(all(feature = "std", any(feature = "v5", feature = "v5-digest")))]
+    Name(crate::name::Error),
+
+    /// An error occurred while serializing a value to hash it into a
+    /// [`Uuid`].
+    ///
+    /// [`Uuid`]: struct.Uuid.html
+    #[cfg(all(
+        feature = "serde",
+        feature = "std",
+        any(feature = "v5", feature = "v5-digest")
+    ))]
+    Canonical(crate::canonical::Error),
+}
+
+impl Error {
+    /// Returns the kind of this error.
+    pub fn kind(&self) -> ErrorKind {
+        match self.0 {
+            Inner::Build(_) => ErrorKind::InvalidBytesLength,
+            Inner::Parser(parser::Error::InvalidCharacter { .. }) => {
+                ErrorKind::InvalidCharacter
+            }
+            Inner::Parser(parser::Error::InvalidGroupCount { .. }) => {
+                ErrorKind::InvalidGroupCount
+            }
+            Inner::Parser(parser::Error::InvalidGroupLength { .. }) => {
+                ErrorKind::InvalidGroupLength
+            }
+            Inner::Parser(parser::Error::InvalidLength { .. }) => {
+                ErrorKind::InvalidLength
+            }
+            Inner::Parser(parser::Error::UnexpectedEnd { .. }) => {
+                ErrorKind::UnexpectedEnd
+            }
+            #[cfg(all(
+                feature = "std",
+                any(feature = "v5", feature = "v5-digest")
+            ))]
+            Inner::Name(_) => ErrorKind::InvalidName,
+            #[cfg(all(
+                feature = "serde",
+                feature = "std",
+                any(feature = "v5", feature = "v5-digest")
+            ))]
+            Inner::Canonical(_) => ErrorKind::Serialization,
+        }
+    }
+
+    /// Returns the invalid character, for an
+    /// [`ErrorKind::InvalidCharacter`] error.
+    ///
+    /// [`ErrorKind::InvalidCharacter`]: enum.ErrorKind.html#variant.InvalidCharacter
+    pub fn character(&self) -> Option<char> {
+        match self.0 {
+            Inner::Parser(parser::Error::InvalidCharacter {
+                found, ..
+            }) => Some(found),
+            _ => None,
+        }
+    }
+
+    /// Returns the position of the invalid character, for an
+    /// [`ErrorKind::InvalidCharacter`] error, or of the end of the input,
+    /// for an [`ErrorKind::UnexpectedEnd`] error.
+    ///
+    /// The position is in the units of the parsed input, such as bytes for
+    /// [`Uuid::parse_str`].
+    ///
+    /// [`ErrorKind::InvalidCharacter`]: enum.ErrorKind.html#variant.InvalidCharacter
+    /// [`ErrorKind::UnexpectedEnd`]: enum.ErrorKind.html#variant.UnexpectedEnd
+    /// [`Uuid::parse_str`]: struct.Uuid.html#method.parse_str
+    pub fn index(&self) -> Option<usize> {
+        match self.0 {
+            Inner::Parser(parser::Error::InvalidCharacter {
+                index, ..
+            })
+            | Inner::Parser(parser::Error::UnexpectedEnd { index, .. }) => {
+                Some(index)
+            }
+            _ => None,
+        }
+    }
+
+    /// Returns the (0-indexed) number of the group with the wrong number of
+    /// digits, for an [`ErrorKind::InvalidGroupLength`] error.
+    ///
+    /// [`ErrorKind::InvalidGroupLength`]: enum.ErrorKind.html#variant.InvalidGroupLength
+    pub fn group(&self) -> Option<usize> {
+        match self.0 {
+            Inner::Parser(parser::Error::InvalidGroupLength {
+                group, ..
+            }) => Some(group),
+            _ => None,
+        }
+    }
+
+    /// Returns the lengths that would have been valid, for an error about
+    /// a wrong length.
+    ///
+    /// These are the lengths of the string for an
+    /// [`ErrorKind::InvalidLength`] error, of the group for an
+    /// [`ErrorKind::InvalidGroupLength`] error, of the slice for an
+    /// [`ErrorKind::InvalidBytesLength`] error, and the numbers of groups for
+    /// an [`ErrorKind::InvalidGroupCount`] error.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use uuid::Uuid;
+    ///
+    /// let err =
+    ///     Uuid::parse_str("67e5504410b1426f9247bb680e5fe0c").unwrap_err();
+    ///
+    /// assert_eq!(err.expected_len(), Some(&[36, 32, 38][..]));
+    /// assert_eq!(err.found_len(), Some(31));
+    /// ```
+    ///
+    /// [`ErrorKind::InvalidLength`]: enum.ErrorKind.html#variant.InvalidLength
+    /// [`ErrorKind::InvalidGroupLength`]: enum.ErrorKind.html#variant.InvalidGroupLength
+    /// [`ErrorKind::InvalidBytesLength`]: enum.ErrorKind.html#variant.InvalidBytesLength
+    /// [`ErrorKind::InvalidGroupCount`]: enum.ErrorKind.html#variant.InvalidGroupCount
+    pub fn expected_len(&self) -> Option<&[usize]> {
+        let expected = match self.0 {
+            Inner::Build(ref err) => {
+                return Some(slice::from_ref(&err.expected))
+            }
+            Inner::Parser(parser::Error::InvalidGroupCount {
+                ref expected,
+                ..
+            })
+            | Inner::Parser(parser::Error::InvalidGroupLength {
+                ref expected,
+                ..
+            })
+            | Inner::Parser(parser::Error::InvalidLength {
+                ref expected,
+                ..
+            }) => expected,
+            _ => return None,
+        };
+
+        match *expected {
+            parser::error::ExpectedLength::Any(lengths) => Some(lengths),
+            parser::error::ExpectedLength::Exact(ref length) => {
+                Some(slice::from_ref(length))
+            }
+        }
+    }
+
+    /// Returns the length that was found, for an error about a wrong
+    /// length.
+    ///
+    /// See [`expected_len`] for the errors this applies to.
+    ///
+    /// [`expected_len`]: #method.expected_len
+    pub fn found_len(&self) -> Option<usize> {
+        match self.0 {
+            Inner::Build(ref err) => Some(err.found),
+            Inner::Parser(parser::Error::InvalidGroupCount {
+                found, ..
+            })
+            | Inner::Parser(parser::Error::InvalidGroupLength {
+                found, ..
+            })
+            | Inner::Parser(parser::Error::InvalidLength { found, .. }) => {
+                Some(found)
+            }
+            _ => None,
+        }
+    }
+
+    /// Returns the underlying parser error, if this is one.
+    pub(crate) fn as_parser(&self) -> Option<parser::Error> {
+        match self.0 {
+            Inner::Parser(err) => Some(err),
+            _ => None,
+        }
+    }
 }
 
 impl From<builder::Error> for Error {
@@ -40,11 +274,40 @@ impl From<parser::Error> for Error {
     }
 }
 
+#[cfg(all(feature = "std", any(feature = "v5", feature = "v5-digest")))]
+impl From<crate::name::Error> for Error {
+    fn from(err: crate::name::Error) -> Self {
+        Error(Inner::Name(err))
+    }
+}
+
+#[cfg(all(
+    feature = "serde",
+    feature = "std",
+    any(feature = "v5", feature = "v5-digest")
+))]
+impl From<crate::canonical::Error> for Error {
+    fn from(err: crate::canonical::Error) -> Self {
+        Error(Inner::Canonical(err))
+    }
+}
+
 impl fmt::Display for Error {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self.0 {
             Inner::Build(ref err) => fmt::Display::fmt(&err, f),
             Inner::Parser(ref err) => fmt::Display::fmt(&err, f),
+            #[cfg(all(
+                feature = "std",
+                any(feature = "v5", feature = "v5-digest")
+            ))]
+            Inner::Name(ref err) => fmt::Display::fmt(&err, f),
+            #[cfg(all(
+                feature = "serde",
+                feature = "std",
+                any(feature = "v5", feature = "v5-digest")
+            ))]
+            Inner::Canonical(ref err) => fmt::Display::fmt(&err, f),
         }
     }
 }
@@ -59,6 +322,13 @@ mod std_support {
             match self.0 {
                 Inner::Build(ref err) => Some(err),
                 Inner::Parser(ref err) => Some(err),
+                #[cfg(any(feature = "v5", feature = "v5-digest"))]
+                Inner::Name(ref err) => Some(err),
+                #[cfg(all(
+                    feature = "serde",
+                    any(feature = "v5", feature = "v5-digest")
+                ))]
+                Inner::Canonical(ref err) => Some(err),
             }
         }
     }
@@ -77,3 +347,97 @@ mod test_util {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Uuid;
+
+    #[test]
+    fn test_invalid_character() {
+        let err = Uuid::parse_str("67e55044-10b1-426f-9247-bb680e5fe0cX")
+            .unwrap_err();
+
+        assert_eq!(err.kind(), ErrorKind::InvalidCharacter);
+        assert_eq!(err.character(), Some('X'));
+        assert_eq!(err.index(), Some(35));
+        assert_eq!(err.group(), None);
+        assert_eq!(err.expected_len(), None);
+        assert_eq!(err.found_len(), None);
+    }
+
+    #[test]
+    fn test_invalid_group_count() {
+        let err = Uuid::parse_str("67e55044-10b1426f9247bb680e5fe0c8abc")
+            .unwrap_err();
+
+        assert_eq!(err.kind(), ErrorKind::InvalidGroupCount);
+        assert_eq!(err.character(), None);
+        assert_eq!(err.index(), None);
+        assert_eq!(err.group(), None);
+        assert_eq!(err.expected_len(), Some(&[1, 5][..]));
+        assert_eq!(err.found_len(), Some(2));
+    }
+
+    #[test]
+    fn test_invalid_group_length() {
+        let err = Uuid::parse_str("F9168C5E-CEB2-4faa-B6BF1-02BF39FA1E4")
+            .unwrap_err();
+
+        assert_eq!(err.kind(), ErrorKind::InvalidGroupLength);
+        assert_eq!(err.character(), None);
+        assert_eq!(err.index(), None);
+        assert_eq!(err.group(), Some(3));
+        assert_eq!(err.expected_len(), Some(&[4][..]));
+        assert_eq!(err.found_len(), Some(5));
+    }
+
+    #[test]
+    fn test_invalid_length() {
+        let err =
+            Uuid::parse_str("67e5504410b1426f9247bb680e5fe0c").unwrap_err();
+
+        assert_eq!(err.kind(), ErrorKind::InvalidLength);
+        assert_eq!(err.character(), None);
+        assert_eq!(err.index(), None);
+        assert_eq!(err.group(), None);
+        assert_eq!(err.expected_len(), Some(&[36, 32, 38][..]));
+        assert_eq!(err.found_len(), Some(31));
+    }
+
+    #[test]
+    fn test_unexpected_end() {
+        let err = crate::adapter::Initializer::parse_str("{0x6b29fc40, 0xca47")
+            .unwrap_err();
+
+        assert_eq!(err.kind(), ErrorKind::UnexpectedEnd);
+        assert_eq!(err.character(), None);
+        assert_eq!(err.index(), Some(19));
+        assert_eq!(err.group(), None);
+        assert_eq!(err.expected_len(), None);
+        assert_eq!(err.found_len(), None);
+    }
+
+    #[test]
+    fn test_invalid_bytes_length() {
+        let err = Uuid::from_slice(&[0; 15]).unwrap_err();
+
+        assert_eq!(err.kind(), ErrorKind::InvalidBytesLength);
+        assert_eq!(err.character(), None);
+        assert_eq!(err.index(), None);
+        assert_eq!(err.group(), None);
+        assert_eq!(err.expected_len(), Some(&[16][..]));
+        assert_eq!(err.found_len(), Some(15));
+    }
+
+    #[cfg(all(feature = "std", any(feature = "v5", feature = "v5-digest")))]
+    #[test]
+    fn test_invalid_name() {
+        let err = Uuid::from_dns_name("").unwrap_err();
+
+        assert_eq!(err.kind(), ErrorKind::InvalidName);
+        assert_eq!(err.character(), None);
+        assert_eq!(err.expected_len(), None);
+        assert_eq!(err.found_len(), None);
+    }
+}
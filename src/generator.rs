@@ -0,0 +1,429 @@
+//! Sources of UUIDs that can be swapped out, such as for testing.
+//!
+//! Code that calls [`Uuid::new_v4`] directly always gets a fresh random
+//! UUID, which makes its output hard to test. Taking a [`UuidGenerator`]
+//! instead lets callers choose where UUIDs come from: a random or
+//! time-based generator in production, and a predictable one such as
+//! [`Sequential`] or [`Seeded`] in tests.
+//!
+//! # Examples
+//!
+//! ```
+//! use uuid::{generator::{Sequential, UuidGenerator}, Uuid};
+//!
+//! struct Orders<'a> {
+//!     ids: &'a dyn UuidGenerator,
+//! }
+//!
+//! impl<'a> Orders<'a> {
+//!     fn create(&self) -> Uuid {
+//!         self.ids.next()
+//!     }
+//! }
+//!
+//! let orders = Orders { ids: &Sequential::new(1) };
+//!
+//! assert_eq!(orders.create(), Uuid::from_u128(1));
+//! assert_eq!(orders.create(), Uuid::from_u128(2));
+//! ```
+//!
+//! [`Uuid::new_v4`]: ../struct.Uuid.html#method.new_v4
+//! [`UuidGenerator`]: trait.UuidGenerator.html
+//! [`Sequential`]: struct.Sequential.html
+//! [`Seeded`]: struct.Seeded.html
+
+use crate::prelude::*;
+#[cfg(target_has_atomic = "ptr")]
+use core::sync::atomic;
+
+/// A count of the UUIDs generated: 64 bits where the target supports it,
+/// and pointer-sized otherwise.
+#[cfg(target_has_atomic = "64")]
+type Counter = atomic::AtomicU64;
+#[cfg(all(target_has_atomic = "ptr", not(target_has_atomic = "64")))]
+type Counter = atomic::AtomicUsize;
+
+/// A source of UUIDs.
+///
+/// Implementations take `&self` so that a single generator can be shared,
+/// including as a `&dyn UuidGenerator`.
+pub trait UuidGenerator {
+    /// Returns the next UUID from this generator.
+    fn next(&self) -> Uuid;
+}
+
+impl<T: UuidGenerator + ?Sized> UuidGenerator for &T {
+    fn next(&self) -> Uuid {
+        (**self).next()
+    }
+}
+
+/// A generator of random (V4) UUIDs, using [`Uuid::new_v4`].
+///
+/// Note that usage of this type requires the `v4` feature of this crate to
+/// be enabled.
+///
+/// [`Uuid::new_v4`]: ../struct.Uuid.html#method.new_v4
+#[cfg(feature = "v4")]
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Random;
+
+#[cfg(feature = "v4")]
+impl UuidGenerator for Random {
+    fn next(&self) -> Uuid {
+        Uuid::new_v4()
+    }
+}
+
+/// A generator of time-based (V1) UUIDs, using the system clock, a
+/// [`ClockSequence`] and a node ID.
+///
+/// Note that usage of this type requires the `v1` and `std` features of this
+/// crate to be enabled.
+///
+/// # Examples
+///
+/// ```
+/// use uuid::{
+///     generator::{UuidGenerator, V1},
+///     v1::Context,
+///     Version,
+/// };
+///
+/// let generator = V1::new(Context::new(0), [1, 2, 3, 4, 5, 6]);
+///
+/// assert_eq!(generator.next().get_version(), Some(Version::Mac));
+/// ```
+///
+/// [`ClockSequence`]: ../v1/trait.ClockSequence.html
+#[cfg(all(feature = "v1", feature = "std"))]
+#[derive(Debug)]
+pub struct V1<C> {
+    context: C,
+    node_id: [u8; 6],
+}
+
+#[cfg(all(feature = "v1", feature = "std"))]
+impl<C> V1<C> {
+    /// Creates a generator that uses `context` for its clock sequence and
+    /// `node_id` for the node portion of each UUID.
+    pub const fn new(context: C, node_id: [u8; 6]) -> Self {
+        V1 { context, node_id }
+    }
+}
+
+#[cfg(all(feature = "v1", feature = "std"))]
+impl<C: crate::v1::ClockSequence> UuidGenerator for V1<C> {
+    fn next(&self) -> Uuid {
+        let now = crate::std::time::SystemTime::now()
+            .duration_since(crate::std::time::UNIX_EPOCH)
+            .expect("system clock is set before the Unix epoch");
+
+        let ts = crate::v1::Timestamp::from_unix(
+            &self.context,
+            now.as_secs(),
+            now.subsec_nanos(),
+        );
+
+        Uuid::new_v1(ts, &self.node_id).expect("node ID is 6 bytes")
+    }
+}
+
+/// A generator of time-ordered (V7) UUIDs, using the system clock and
+/// [`Uuid::new_v7`].
+///
+/// Note that usage of this type requires the `v7` and `std` features of this
+/// crate to be enabled.
+///
+/// # Examples
+///
+/// ```
+/// use uuid::{
+///     generator::{UuidGenerator, V7},
+///     Version,
+/// };
+///
+/// assert_eq!(V7.next().get_version(), Some(Version::SortRand));
+/// ```
+///
+/// [`Uuid::new_v7`]: ../struct.Uuid.html#method.new_v7
+#[cfg(all(feature = "v7", feature = "std"))]
+#[derive(Debug, Clone, Copy, Default)]
+pub struct V7;
+
+#[cfg(all(feature = "v7", feature = "std"))]
+impl UuidGenerator for V7 {
+    fn next(&self) -> Uuid {
+        let now = crate::std::time::SystemTime::now()
+            .duration_since(crate::std::time::UNIX_EPOCH)
+            .expect("system clock is set before the Unix epoch");
+
+        Uuid::new_v7(now.as_millis() as u64)
+    }
+}
+
+/// A generator of UUIDs that count up from a starting value.
+///
+/// Each call to [`next`] returns the UUID whose `u128` value is one more
+/// than the last, wrapping around to the nil UUID after the one with every
+/// bit set. The UUIDs aren't of any version; they're meant to be easy to
+/// predict and read in tests.
+///
+/// # Examples
+///
+/// ```
+/// use uuid::{generator::{Sequential, UuidGenerator}, Uuid};
+///
+/// let generator = Sequential::new(0);
+///
+/// assert_eq!(
+///     generator.next().to_hyphenated().to_string(),
+///     "00000000-0000-0000-0000-000000000000"
+/// );
+/// assert_eq!(
+///     generator.next().to_hyphenated().to_string(),
+///     "00000000-0000-0000-0000-000000000001"
+/// );
+/// ```
+///
+/// Note that this type is only available on targets that support atomic
+/// operations on pointer-sized integers. The number of UUIDs generated is
+/// kept in a 64-bit counter, or a pointer-sized one on targets without
+/// 64-bit atomics. On those, such as some 32-bit targets, the sequence
+/// starts over from `start` after 2^32 UUIDs.
+///
+/// [`next`]: trait.UuidGenerator.html#tymethod.next
+#[cfg(target_has_atomic = "ptr")]
+#[derive(Debug)]
+pub struct Sequential {
+    start: u128,
+    count: Counter,
+}
+
+#[cfg(target_has_atomic = "ptr")]
+impl Sequential {
+    /// Creates a generator whose first UUID has the value `start`.
+    pub const fn new(start: u128) -> Self {
+        Sequential {
+            start,
+            count: Counter::new(0),
+        }
+    }
+}
+
+#[cfg(target_has_atomic = "ptr")]
+impl UuidGenerator for Sequential {
+    fn next(&self) -> Uuid {
+        let count = self.count.fetch_add(1, atomic::Ordering::Relaxed);
+
+        Uuid::from_u128(self.start.wrapping_add(count as u128))
+    }
+}
+
+/// A generator of random-looking (V4) UUIDs in a reproducible sequence.
+///
+/// The sequence is determined entirely by a seed, so two generators created
+/// with the same seed produce the same UUIDs in the same order. This keeps
+/// IDs stable across runs of snapshot tests, without them all looking alike.
+/// Seeds can be given as a `u64` or derived from a name, such as the name of
+/// the test.
+///
+/// The UUIDs are built from the [SplitMix64] sequence and are not suitable
+/// where unpredictable IDs are needed.
+///
+/// # Examples
+///
+/// ```
+/// use uuid::{generator::{Seeded, UuidGenerator}, Version};
+///
+/// let a = Seeded::from_name("orders::test_create");
+/// let b = Seeded::from_name("orders::test_create");
+///
+/// let first = a.next();
+///
+/// assert_eq!(first.get_version(), Some(Version::Random));
+/// assert_eq!(first, b.next());
+/// assert_ne!(a.next(), first);
+/// ```
+///
+/// Note that this type is only available on targets that support atomic
+/// operations on pointer-sized integers. As with [`Sequential`], the
+/// sequence starts over after 2^32 UUIDs on targets without 64-bit atomics.
+///
+/// [SplitMix64]: https://prng.di.unimi.it/splitmix64.c
+/// [`Sequential`]: struct.Sequential.html
+#[cfg(target_has_atomic = "ptr")]
+#[derive(Debug)]
+pub struct Seeded {
+    seed: u64,
+    count: Counter,
+}
+
+#[cfg(target_has_atomic = "ptr")]
+impl Seeded {
+    /// Creates a generator from a `u64` seed.
+    pub const fn new(seed: u64) -> Self {
+        Seeded {
+            seed,
+            count: Counter::new(0),
+        }
+    }
+
+    /// Creates a generator seeded from a name, such as the name of a test.
+    ///
+    /// The seed is the 64-bit FNV-1a hash of `name`.
+    pub const fn from_name(name: &str) -> Self {
+        const FNV_OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+        const FNV_PRIME: u64 = 0x0000_0100_0000_01b3;
+
+        let name = name.as_bytes();
+        let mut hash = FNV_OFFSET_BASIS;
+        let mut i = 0;
+        while i < name.len() {
+            hash = (hash ^ name[i] as u64).wrapping_mul(FNV_PRIME);
+            i += 1;
+        }
+
+        Seeded::new(hash)
+    }
+}
+
+#[cfg(target_has_atomic = "ptr")]
+impl UuidGenerator for Seeded {
+    fn next(&self) -> Uuid {
+        const GAMMA: u64 = 0x9e37_79b9_7f4a_7c15;
+
+        fn mix(mut z: u64) -> u64 {
+            z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+            z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+            z ^ (z >> 31)
+        }
+
+        // The cast only widens the count where `Counter` is pointer-sized.
+        #[allow(clippy::unnecessary_cast)]
+        let count = self.count.fetch_add(1, atomic::Ordering::Relaxed) as u64;
+        let state = self
+            .seed
+            .wrapping_add(count.wrapping_mul(GAMMA.wrapping_mul(2)));
+
+        let high = mix(state.wrapping_add(GAMMA));
+        let low = mix(state.wrapping_add(GAMMA).wrapping_add(GAMMA));
+
+        crate::Builder::from_u128(u128::from(high) << 64 | u128::from(low))
+            .set_variant(Variant::RFC4122)
+            .set_version(Version::Random)
+            .build()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::std::string::ToString;
+
+    #[test]
+    #[cfg(target_has_atomic = "ptr")]
+    fn test_sequential() {
+        let generator = Sequential::new(u128::MAX - 1);
+
+        assert_eq!(generator.next(), Uuid::from_u128(u128::MAX - 1));
+        assert_eq!(generator.next(), Uuid::from_u128(u128::MAX));
+        assert_eq!(generator.next(), Uuid::nil());
+    }
+
+    #[test]
+    #[cfg(target_has_atomic = "ptr")]
+    fn test_dyn() {
+        let generator = Sequential::new(7);
+        let generator: &dyn UuidGenerator = &generator;
+
+        assert_eq!(generator.next(), Uuid::from_u128(7));
+        assert_eq!((&generator).next(), Uuid::from_u128(8));
+    }
+
+    #[test]
+    #[cfg(target_has_atomic = "ptr")]
+    fn test_seeded() {
+        let generator = Seeded::new(0);
+
+        assert_eq!(
+            generator.next().to_hyphenated().to_string(),
+            "e220a839-7b1d-4daf-ae78-9e6aa1b965f4"
+        );
+        assert_eq!(
+            generator.next().to_hyphenated().to_string(),
+            "06c45d18-8009-454f-b88b-b8a8724c81ec"
+        );
+
+        let generator = Seeded::new(42);
+
+        assert_eq!(
+            generator.next().to_hyphenated().to_string(),
+            "bdd73226-2feb-4e95-a8ef-e333b266f103"
+        );
+    }
+
+    #[test]
+    #[cfg(target_has_atomic = "ptr")]
+    fn test_seeded_distinct() {
+        let generator = Seeded::from_name("test_seeded_distinct");
+        let replay = Seeded::from_name("test_seeded_distinct");
+        let other = Seeded::from_name("test_seeded_distinct_other");
+
+        let mut seen = [Uuid::nil(); 256];
+        for i in 0..seen.len() {
+            let uuid = generator.next();
+
+            assert_eq!(uuid.get_version(), Some(Version::Random));
+            assert_eq!(uuid.get_variant(), Some(Variant::RFC4122));
+            assert_eq!(uuid, replay.next());
+            assert_ne!(uuid, other.next());
+            assert!(!seen[..i].contains(&uuid));
+
+            seen[i] = uuid;
+        }
+    }
+
+    #[test]
+    #[cfg(target_has_atomic = "ptr")]
+    fn test_seeded_from_name() {
+        assert_eq!(Seeded::from_name("").seed, 0xcbf2_9ce4_8422_2325);
+        assert_eq!(Seeded::from_name("a").seed, 0xaf63_dc4c_8601_ec8c);
+    }
+
+    #[test]
+    #[cfg(feature = "v4")]
+    fn test_random() {
+        let a = Random.next();
+        let b = Random.next();
+
+        assert_eq!(a.get_version(), Some(Version::Random));
+        assert_ne!(a, b);
+    }
+
+    #[test]
+    #[cfg(all(feature = "v1", feature = "std"))]
+    fn test_v1() {
+        let node = [1, 2, 3, 4, 5, 6];
+        let generator = V1::new(crate::v1::Context::new(0), node);
+
+        let a = generator.next();
+        let b = generator.next();
+
+        assert_eq!(a.get_version(), Some(Version::Mac));
+        assert_eq!(&a.as_bytes()[10..], &node);
+        assert_ne!(a, b);
+    }
+
+    #[test]
+    #[cfg(all(feature = "v7", feature = "std"))]
+    fn test_v7() {
+        let a = V7.next();
+        let b = V7.next();
+
+        assert_eq!(a.get_version(), Some(Version::SortRand));
+        assert!(a.as_bytes()[..6] <= b.as_bytes()[..6]);
+        assert_ne!(a, b);
+    }
+}
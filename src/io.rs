@@ -0,0 +1,597 @@
+//! Reading and writing streams of UUIDs.
+//!
+//! A stream is either text, with one [`Uuid`] string per line, or binary,
+//! with each [`Uuid`] stored as 16 bytes and nothing between them. Neither
+//! [`UuidReader`] nor [`UuidWriter`] allocates per record.
+//!
+//! # Examples
+//!
+//! Converting a text dump to binary records:
+//!
+//! ```
+//! use uuid::io::{UuidReader, UuidWriter};
+//!
+//! let dump = "67e55044-10b1-426f-9247-bb680e5fe0c8\n\
+//!             urn:uuid:00000000-0000-0000-0000-000000000000\n";
+//!
+//! let mut writer = UuidWriter::binary(Vec::new());
+//! for uuid in UuidReader::text(dump.as_bytes()) {
+//!     writer.write_uuid(&uuid?)?;
+//! }
+//!
+//! let records = writer.into_inner();
+//! assert_eq!(records.len(), 32);
+//! # Ok::<(), Box<dyn std::error::Error>>(())
+//! ```
+//!
+//! [`Uuid`]: ../struct.Uuid.html
+//! [`UuidReader`]: struct.UuidReader.html
+//! [`UuidWriter`]: struct.UuidWriter.html
+
+use crate::adapter::{self, Format};
+use crate::std::io::{self, BufRead, Write};
+use crate::std::{error, fmt};
+use crate::{parser, Uuid};
+
+/// The longest line that could hold a [`Uuid`], a urn ending in `\r\n`.
+///
+/// [`Uuid`]: ../struct.Uuid.html
+const MAX_LINE: usize = adapter::Urn::LENGTH + 1;
+
+/// An error reading a [`Uuid`] from a [`UuidReader`].
+///
+/// [`Uuid`]: ../struct.Uuid.html
+/// [`UuidReader`]: struct.UuidReader.html
+#[derive(Debug)]
+pub struct Error {
+    line: u64,
+    inner: Inner,
+}
+
+#[derive(Debug)]
+enum Inner {
+    Io(io::Error),
+    Uuid(crate::Error),
+}
+
+impl Error {
+    /// The line the error occurred on, counting from 1.
+    ///
+    /// For binary streams, this is the number of the record instead.
+    pub fn line(&self) -> u64 {
+        self.line
+    }
+
+    /// Returns the error from the underlying reader, if there was one.
+    pub fn io_error(&self) -> Option<&io::Error> {
+        match self.inner {
+            Inner::Io(ref err) => Some(err),
+            Inner::Uuid(_) => None,
+        }
+    }
+
+    /// Returns the error from parsing the [`Uuid`], if there was one.
+    ///
+    /// [`Uuid`]: ../struct.Uuid.html
+    pub fn uuid_error(&self) -> Option<&crate::Error> {
+        match self.inner {
+            Inner::Io(_) => None,
+            Inner::Uuid(ref err) => Some(err),
+        }
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.inner {
+            Inner::Io(ref err) => write!(f, "line {}: {}", self.line, err),
+            Inner::Uuid(ref err) => write!(f, "line {}: {}", self.line, err),
+        }
+    }
+}
+
+impl error::Error for Error {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match self.inner {
+            Inner::Io(ref err) => Some(err),
+            Inner::Uuid(ref err) => Some(err),
+        }
+    }
+}
+
+impl From<Error> for io::Error {
+    fn from(err: Error) -> Self {
+        let kind = match err.inner {
+            Inner::Io(ref err) => err.kind(),
+            Inner::Uuid(_) => io::ErrorKind::InvalidData,
+        };
+
+        io::Error::new(kind, err)
+    }
+}
+
+/// Reads a stream of [`Uuid`]s.
+///
+/// Text streams may mix any of the formats supported by [`parse_str`], with
+/// one per line. Lines end in `\n` or `\r\n`, and the last line doesn't
+/// need to end in either. Empty lines, and lines too long to be a UUID,
+/// are errors.
+///
+/// Binary streams hold each UUID as its 16 bytes. A stream whose length
+/// isn't a multiple of 16 ends in an error.
+///
+/// `UuidReader` is also an [`Iterator`] over the UUIDs in the stream.
+///
+/// # Examples
+///
+/// ```
+/// use uuid::io::UuidReader;
+///
+/// let mut reader = UuidReader::text(
+///     "67e5504410b1426f9247bb680e5fe0c8\r\nnot a uuid\r\n".as_bytes(),
+/// );
+///
+/// assert!(reader.read_uuid()?.is_some());
+///
+/// let err = reader.read_uuid().unwrap_err();
+/// assert_eq!(err.line(), 2);
+/// assert_eq!(
+///     err.to_string(),
+///     "line 2: invalid length: expected one of [36, 32, 38], found 10"
+/// );
+/// # Ok::<(), uuid::io::Error>(())
+/// ```
+///
+/// [`Uuid`]: ../struct.Uuid.html
+/// [`parse_str`]: ../struct.Uuid.html#method.parse_str
+/// [`Iterator`]: https://doc.rust-lang.org/std/iter/trait.Iterator.html
+#[derive(Debug)]
+pub struct UuidReader<R> {
+    inner: R,
+    binary: bool,
+    line: u64,
+}
+
+impl<R: BufRead> UuidReader<R> {
+    /// Creates a reader of newline-delimited [`Uuid`] strings.
+    ///
+    /// [`Uuid`]: ../struct.Uuid.html
+    pub fn text(inner: R) -> Self {
+        UuidReader {
+            inner,
+            binary: false,
+            line: 0,
+        }
+    }
+
+    /// Creates a reader of 16-byte [`Uuid`] records.
+    ///
+    /// [`Uuid`]: ../struct.Uuid.html
+    pub fn binary(inner: R) -> Self {
+        UuidReader {
+            inner,
+            binary: true,
+            line: 0,
+        }
+    }
+
+    /// Reads the next [`Uuid`], or `None` at the end of the stream.
+    ///
+    /// [`Uuid`]: ../struct.Uuid.html
+    pub fn read_uuid(&mut self) -> Result<Option<Uuid>, Error> {
+        let result = if self.binary {
+            self.read_record()
+        } else {
+            self.read_line()
+        };
+
+        match result {
+            Ok(None) => Ok(None),
+            Ok(Some(uuid)) => {
+                self.line += 1;
+                Ok(Some(uuid))
+            }
+            Err(inner) => {
+                self.line += 1;
+                Err(Error {
+                    line: self.line,
+                    inner,
+                })
+            }
+        }
+    }
+
+    fn read_line(&mut self) -> Result<Option<Uuid>, Inner> {
+        let mut line = [0u8; MAX_LINE];
+        let mut len = 0;
+        let mut last = None;
+
+        loop {
+            let available = match self.inner.fill_buf() {
+                Ok(available) => available,
+                Err(ref err) if err.kind() == io::ErrorKind::Interrupted => {
+                    continue
+                }
+                Err(err) => return Err(Inner::Io(err)),
+            };
+
+            if available.is_empty() {
+                if len == 0 {
+                    return Ok(None);
+                }
+                break;
+            }
+
+            let newline = available.iter().position(|&b| b == b'\n');
+            let chunk = &available[..newline.unwrap_or(available.len())];
+
+            // Only the start of an overlong line is kept, since it can't
+            // be a UUID anyway.
+            if len < MAX_LINE {
+                let n = chunk.len().min(MAX_LINE - len);
+                line[len..len + n].copy_from_slice(&chunk[..n]);
+            }
+            len += chunk.len();
+            last = chunk.last().copied().or(last);
+
+            let consumed = chunk.len() + newline.map_or(0, |_| 1);
+            self.inner.consume(consumed);
+
+            if newline.is_some() {
+                break;
+            }
+        }
+
+        if last == Some(b'\r') {
+            len -= 1;
+        }
+
+        if len > adapter::Urn::LENGTH {
+            return Err(Inner::Uuid(
+                parser::Error::InvalidLength {
+                    expected: parser::EXPECTED_LENGTHS,
+                    found: len,
+                }
+                .into(),
+            ));
+        }
+
+        Uuid::try_parse_ascii(&line[..len])
+            .map(Some)
+            .map_err(Inner::Uuid)
+    }
+
+    fn read_record(&mut self) -> Result<Option<Uuid>, Inner> {
+        let mut bytes = [0u8; 16];
+        let mut len = 0;
+
+        while len < bytes.len() {
+            match self.inner.read(&mut bytes[len..]) {
+                Ok(0) => break,
+                Ok(n) => len += n,
+                Err(ref err) if err.kind() == io::ErrorKind::Interrupted => {}
+                Err(err) => return Err(Inner::Io(err)),
+            }
+        }
+
+        if len == 0 {
+            return Ok(None);
+        }
+
+        Uuid::from_slice(&bytes[..len])
+            .map(Some)
+            .map_err(Inner::Uuid)
+    }
+}
+
+impl<R> UuidReader<R> {
+    /// The number of lines, or binary records, read so far.
+    pub fn line(&self) -> u64 {
+        self.line
+    }
+
+    /// Gets a reference to the underlying reader.
+    pub fn get_ref(&self) -> &R {
+        &self.inner
+    }
+
+    /// Gets a mutable reference to the underlying reader.
+    pub fn get_mut(&mut self) -> &mut R {
+        &mut self.inner
+    }
+
+    /// Unwraps this `UuidReader`, returning the underlying reader.
+    pub fn into_inner(self) -> R {
+        self.inner
+    }
+}
+
+impl<R: BufRead> Iterator for UuidReader<R> {
+    type Item = Result<Uuid, Error>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.read_uuid().transpose()
+    }
+}
+
+/// Writes a stream of [`Uuid`]s that can be read back by a [`UuidReader`].
+///
+/// Text streams hold one lower-case [`Uuid`] string per line, each ending
+/// in `\n`. Binary streams hold each [`Uuid`] as its 16 bytes.
+///
+/// Every [`Uuid`] is written with a single call to [`write_all`], so
+/// wrap unbuffered writers, such as files, in a [`BufWriter`].
+///
+/// # Examples
+///
+/// ```
+/// use uuid::{adapter::Format, io::UuidWriter, Uuid};
+///
+/// let mut writer = UuidWriter::text(Vec::new(), Format::Simple);
+/// writer.write_uuid(&Uuid::nil())?;
+///
+/// assert_eq!(writer.into_inner(), b"00000000000000000000000000000000\n");
+/// # Ok::<(), std::io::Error>(())
+/// ```
+///
+/// [`Uuid`]: ../struct.Uuid.html
+/// [`UuidReader`]: struct.UuidReader.html
+/// [`write_all`]: https://doc.rust-lang.org/std/io/trait.Write.html#method.write_all
+/// [`BufWriter`]: https://doc.rust-lang.org/std/io/struct.BufWriter.html
+#[derive(Debug)]
+pub struct UuidWriter<W> {
+    inner: W,
+    format: Option<Format>,
+}
+
+impl<W: Write> UuidWriter<W> {
+    /// Creates a writer of newline-delimited [`Uuid`] strings in the given
+    /// `format`.
+    ///
+    /// [`Uuid`]: ../struct.Uuid.html
+    pub fn text(inner: W, format: Format) -> Self {
+        UuidWriter {
+            inner,
+            format: Some(format),
+        }
+    }
+
+    /// Creates a writer of 16-byte [`Uuid`] records.
+    ///
+    /// [`Uuid`]: ../struct.Uuid.html
+    pub fn binary(inner: W) -> Self {
+        UuidWriter {
+            inner,
+            format: None,
+        }
+    }
+
+    /// Writes a [`Uuid`] to the stream.
+    ///
+    /// [`Uuid`]: ../struct.Uuid.html
+    pub fn write_uuid(&mut self, uuid: &Uuid) -> io::Result<()> {
+        let format = match self.format {
+            Some(format) => format,
+            None => return self.inner.write_all(uuid.as_bytes()),
+        };
+
+        let mut buffer = [0u8; adapter::Urn::LENGTH + 1];
+        let len = format.encoded_len();
+
+        match format {
+            Format::Simple => uuid.to_simple_ref().encode_lower(&mut buffer),
+            Format::Hyphenated => {
+                uuid.to_hyphenated_ref().encode_lower(&mut buffer)
+            }
+            Format::Urn => uuid.to_urn_ref().encode_lower(&mut buffer),
+            Format::Braced => uuid.to_braced_ref().encode_lower(&mut buffer),
+        };
+        buffer[len] = b'\n';
+
+        self.inner.write_all(&buffer[..=len])
+    }
+
+    /// Flushes the underlying writer.
+    pub fn flush(&mut self) -> io::Result<()> {
+        self.inner.flush()
+    }
+}
+
+impl<W> UuidWriter<W> {
+    /// Gets a reference to the underlying writer.
+    pub fn get_ref(&self) -> &W {
+        &self.inner
+    }
+
+    /// Gets a mutable reference to the underlying writer.
+    pub fn get_mut(&mut self) -> &mut W {
+        &mut self.inner
+    }
+
+    /// Unwraps this `UuidWriter`, returning the underlying writer.
+    pub fn into_inner(self) -> W {
+        self.inner
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::std::io::{BufReader, Cursor, Read};
+    use crate::std::string::ToString;
+    use crate::std::vec::Vec;
+    use crate::test_util;
+    use crate::ErrorKind;
+
+    fn uuids() -> [Uuid; 3] {
+        [test_util::new(), test_util::new2(), Uuid::nil()]
+    }
+
+    fn read_all<R: BufRead>(reader: UuidReader<R>) -> Vec<Uuid> {
+        reader.collect::<Result<_, _>>().unwrap()
+    }
+
+    /// A reader that hands out one byte at a time, and is interrupted
+    /// before each one.
+    struct Trickle<'a> {
+        data: &'a [u8],
+        interrupt: bool,
+    }
+
+    impl<'a> Read for Trickle<'a> {
+        fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+            self.interrupt = !self.interrupt;
+            if self.interrupt {
+                return Err(io::ErrorKind::Interrupted.into());
+            }
+
+            let n = self.data.len().min(buf.len()).min(1);
+            buf[..n].copy_from_slice(&self.data[..n]);
+            self.data = &self.data[n..];
+            Ok(n)
+        }
+    }
+
+    #[test]
+    fn test_text_round_trip() {
+        for &format in &[
+            Format::Simple,
+            Format::Hyphenated,
+            Format::Urn,
+            Format::Braced,
+        ] {
+            let mut writer = UuidWriter::text(Vec::new(), format);
+            for uuid in &uuids() {
+                writer.write_uuid(uuid).unwrap();
+            }
+            let text = writer.into_inner();
+
+            assert_eq!(text.len(), 3 * (format.encoded_len() + 1));
+            assert_eq!(read_all(UuidReader::text(&text[..])), uuids());
+        }
+    }
+
+    #[test]
+    fn test_binary_round_trip() {
+        let mut writer = UuidWriter::binary(Vec::new());
+        for uuid in &uuids() {
+            writer.write_uuid(uuid).unwrap();
+        }
+        let records = writer.into_inner();
+
+        assert_eq!(records.len(), 48);
+        assert_eq!(read_all(UuidReader::binary(&records[..])), uuids());
+    }
+
+    #[test]
+    fn test_read_text_line_endings() {
+        let [a, b, c] = uuids();
+        let text = format!("{}\r\n{}\n{}", a.to_urn(), b.to_simple(), c);
+
+        let mut reader = UuidReader::text(text.as_bytes());
+        assert_eq!(reader.read_uuid().unwrap(), Some(a));
+        assert_eq!(reader.read_uuid().unwrap(), Some(b));
+        assert_eq!(reader.read_uuid().unwrap(), Some(c));
+        assert_eq!(reader.read_uuid().unwrap(), None);
+        assert_eq!(reader.line(), 3);
+
+        assert_eq!(read_all(UuidReader::text(&b""[..])), []);
+    }
+
+    #[test]
+    fn test_read_small_chunks() {
+        let text = format!("{}\r\n{}\n", uuids()[0].to_urn(), uuids()[1]);
+
+        let reader = BufReader::with_capacity(
+            3,
+            Trickle {
+                data: text.as_bytes(),
+                interrupt: false,
+            },
+        );
+        assert_eq!(read_all(UuidReader::text(reader)), &uuids()[..2]);
+
+        let mut records = uuids()[0].as_bytes().to_vec();
+        records.extend_from_slice(uuids()[1].as_bytes());
+        let reader = BufReader::new(Trickle {
+            data: &records,
+            interrupt: false,
+        });
+        assert_eq!(read_all(UuidReader::binary(reader)), &uuids()[..2]);
+    }
+
+    #[test]
+    fn test_read_text_errors() {
+        let text = format!(
+            "{}\n\n{}x\r\n{}\n{}",
+            uuids()[0],
+            uuids()[1],
+            "0".repeat(100),
+            uuids()[2]
+        );
+        let mut reader = UuidReader::text(text.as_bytes());
+
+        assert_eq!(reader.read_uuid().unwrap(), Some(uuids()[0]));
+
+        let err = reader.read_uuid().unwrap_err();
+        assert_eq!(err.line(), 2);
+        assert_eq!(err.uuid_error().unwrap().found_len(), Some(0));
+
+        let err = reader.read_uuid().unwrap_err();
+        assert_eq!(err.line(), 3);
+        assert_eq!(err.uuid_error().unwrap().found_len(), Some(37));
+
+        let err = reader.read_uuid().unwrap_err();
+        assert_eq!(err.line(), 4);
+        assert_eq!(err.uuid_error().unwrap().found_len(), Some(100));
+        assert_eq!(
+            err.to_string(),
+            "line 4: invalid length: expected one of [36, 32, 38], found 100"
+        );
+
+        // Reading carries on after an invalid line.
+        assert_eq!(reader.read_uuid().unwrap(), Some(uuids()[2]));
+        assert_eq!(reader.read_uuid().unwrap(), None);
+    }
+
+    #[test]
+    fn test_read_binary_truncated() {
+        let mut records = uuids()[0].as_bytes().to_vec();
+        records.extend_from_slice(&[1, 2, 3]);
+
+        let mut reader = UuidReader::binary(Cursor::new(records));
+        assert_eq!(reader.read_uuid().unwrap(), Some(uuids()[0]));
+
+        let err = reader.read_uuid().unwrap_err();
+        assert_eq!(err.line(), 2);
+        assert_eq!(
+            err.uuid_error().unwrap().kind(),
+            ErrorKind::InvalidBytesLength
+        );
+        assert_eq!(err.uuid_error().unwrap().found_len(), Some(3));
+    }
+
+    #[test]
+    fn test_read_io_error() {
+        struct Broken;
+
+        impl Read for Broken {
+            fn read(&mut self, _: &mut [u8]) -> io::Result<usize> {
+                Err(io::Error::new(io::ErrorKind::Other, "broken"))
+            }
+        }
+
+        let mut reader = UuidReader::text(BufReader::new(Broken));
+        let err = reader.read_uuid().unwrap_err();
+
+        assert_eq!(err.line(), 1);
+        assert!(err.uuid_error().is_none());
+        assert_eq!(err.to_string(), "line 1: broken");
+
+        let err = io::Error::from(err);
+        assert_eq!(err.kind(), io::ErrorKind::Other);
+
+        let err = UuidReader::text(&b"x\n"[..]).read_uuid().unwrap_err();
+        assert_eq!(io::Error::from(err).kind(), io::ErrorKind::InvalidData);
+    }
+}
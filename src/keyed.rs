@@ -0,0 +1,224 @@
+use crate::prelude::*;
+use hmac::{Hmac, Mac};
+use sha2::Sha256;
+
+impl Uuid {
+    /// Creates a UUID using a name from a namespace, based on the
+    /// HMAC-SHA-256 of the name under a secret `key`.
+    ///
+    /// Unlike [`new_v5`], the result can't be predicted by someone who only
+    /// knows the namespace and name, so keyed UUIDs don't leak the existence
+    /// of resources (such as email addresses) they're derived from. The
+    /// namespace and name are authenticated together, the tag is truncated to
+    /// 128 bits, and then the version and variant bits are set, producing a
+    /// version 8 UUID.
+    ///
+    /// Note that usage of this method requires the `keyed` feature of this
+    /// crate to be enabled.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use uuid::Uuid;
+    ///
+    /// let uuid =
+    ///     Uuid::new_keyed(b"secret", &Uuid::NAMESPACE_DNS, b"www.example.com");
+    ///
+    /// assert_eq!(
+    ///     uuid.to_hyphenated().to_string(),
+    ///     "08cabafd-8983-8f1a-ac74-aea0498452ca"
+    /// );
+    /// ```
+    ///
+    /// [`new_v5`]: #method.new_v5
+    pub fn new_keyed(key: &[u8], namespace: &Uuid, name: &[u8]) -> Uuid {
+        let mut mac = Hmac::<Sha256>::new_from_slice(key)
+            .expect("HMAC accepts keys of any length");
+
+        mac.update(namespace.as_bytes());
+        mac.update(name);
+
+        let buffer = mac.finalize().into_bytes();
+
+        let mut bytes = crate::Bytes::default();
+        bytes.copy_from_slice(&buffer[..16]);
+
+        crate::Builder::from_bytes(bytes)
+            .set_variant(Variant::RFC4122)
+            .set_version(Version::Custom)
+            .build()
+    }
+
+    /// Checks whether this UUID is the keyed UUID of `name` in `namespace`
+    /// under `key`.
+    ///
+    /// The comparison looks at every byte rather than stopping at the first
+    /// difference, so its timing doesn't reveal how much of a guess matched.
+    ///
+    /// Note that usage of this method requires the `keyed` feature of this
+    /// crate to be enabled.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use uuid::Uuid;
+    ///
+    /// let uuid =
+    ///     Uuid::new_keyed(b"secret", &Uuid::NAMESPACE_DNS, b"www.example.com");
+    ///
+    /// assert!(uuid.is_keyed_with(
+    ///     b"secret",
+    ///     &Uuid::NAMESPACE_DNS,
+    ///     b"www.example.com"
+    /// ));
+    /// assert!(!uuid.is_keyed_with(
+    ///     b"guess",
+    ///     &Uuid::NAMESPACE_DNS,
+    ///     b"www.example.com"
+    /// ));
+    /// ```
+    pub fn is_keyed_with(
+        &self,
+        key: &[u8],
+        namespace: &Uuid,
+        name: &[u8],
+    ) -> bool {
+        let expected = Uuid::new_keyed(key, namespace, name);
+
+        self.as_bytes()
+            .iter()
+            .zip(expected.as_bytes())
+            .fold(0, |diff, (a, b)| diff | (a ^ b))
+            == 0
+    }
+
+    /// Finds which of several keys this UUID was created with.
+    ///
+    /// This supports key rotation: pass the current key first, followed by
+    /// any retired keys whose UUIDs may still be in use. The
+    /// index of the first key in `keys` that produces this UUID for `name`
+    /// in `namespace` is returned, or `None` if no key does.
+    ///
+    /// Note that usage of this method requires the `keyed` feature of this
+    /// crate to be enabled.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use uuid::Uuid;
+    ///
+    /// let keys: &[&[u8]] = &[b"current", b"previous"];
+    ///
+    /// let uuid = Uuid::new_keyed(
+    ///     b"previous",
+    ///     &Uuid::NAMESPACE_URL,
+    ///     b"user@example.com",
+    /// );
+    ///
+    /// assert_eq!(
+    ///     uuid.find_key(keys, &Uuid::NAMESPACE_URL, b"user@example.com"),
+    ///     Some(1)
+    /// );
+    /// ```
+    pub fn find_key<K: AsRef<[u8]>>(
+        &self,
+        keys: &[K],
+        namespace: &Uuid,
+        name: &[u8],
+    ) -> Option<usize> {
+        keys.iter()
+            .position(|key| self.is_keyed_with(key.as_ref(), namespace, name))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::std::string::ToString;
+
+    static FIXTURE: &[(&[u8], &Uuid, &str, &str)] = &[
+        (
+            b"secret",
+            &Uuid::NAMESPACE_DNS,
+            "www.example.com",
+            "08cabafd-8983-8f1a-ac74-aea0498452ca",
+        ),
+        (
+            b"secret",
+            &Uuid::NAMESPACE_URL,
+            "mailto:user@example.com",
+            "86d9f115-7e42-8a53-9372-c7e88c437128",
+        ),
+        (
+            b"rotated secret",
+            &Uuid::NAMESPACE_DNS,
+            "www.example.com",
+            "e329bc30-dd76-89c8-9a03-e941095a79a4",
+        ),
+        (
+            b"",
+            &Uuid::NAMESPACE_URL,
+            "mailto:user@example.com",
+            "772e7b46-20e6-86f1-9bc0-11bf576f6b46",
+        ),
+    ];
+
+    #[test]
+    fn test_new() {
+        for &(key, ns, name, expected) in FIXTURE {
+            let uuid = Uuid::new_keyed(key, ns, name.as_bytes());
+
+            assert_eq!(uuid.get_version(), Some(Version::Custom));
+            assert_eq!(uuid.get_variant(), Some(Variant::RFC4122));
+            assert_eq!(uuid.to_hyphenated().to_string(), expected);
+        }
+    }
+
+    #[test]
+    fn test_is_keyed_with() {
+        for &(key, ns, name, _) in FIXTURE {
+            let uuid = Uuid::new_keyed(key, ns, name.as_bytes());
+
+            assert!(uuid.is_keyed_with(key, ns, name.as_bytes()));
+            assert!(!uuid.is_keyed_with(b"wrong", ns, name.as_bytes()));
+            assert!(!uuid.is_keyed_with(key, ns, b"wrong"));
+        }
+    }
+
+    #[test]
+    fn test_find_key() {
+        let keys: &[&[u8]] = &[b"secret", b"rotated secret"];
+
+        let current =
+            Uuid::new_keyed(b"secret", &Uuid::NAMESPACE_DNS, b"example.org");
+        let previous = Uuid::new_keyed(
+            b"rotated secret",
+            &Uuid::NAMESPACE_DNS,
+            b"example.org",
+        );
+        let unknown =
+            Uuid::new_keyed(b"unknown", &Uuid::NAMESPACE_DNS, b"example.org");
+
+        assert_eq!(
+            current.find_key(keys, &Uuid::NAMESPACE_DNS, b"example.org"),
+            Some(0)
+        );
+        assert_eq!(
+            previous.find_key(keys, &Uuid::NAMESPACE_DNS, b"example.org"),
+            Some(1)
+        );
+        assert_eq!(
+            unknown.find_key(keys, &Uuid::NAMESPACE_DNS, b"example.org"),
+            None
+        );
+        assert_eq!(
+            current.find_key::<&[u8]>(
+                &[],
+                &Uuid::NAMESPACE_DNS,
+                b"example.org"
+            ),
+            None
+        );
+    }
+}
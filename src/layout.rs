@@ -0,0 +1,321 @@
+//! Custom (V8) UUIDs made of named bit fields.
+//!
+//! A V8 UUID has 122 bits free for application data; the other 6 hold the
+//! version and variant. Packing data into those bits by hand means skipping
+//! over the version and variant bits, which is easy to get wrong. The
+//! [`v8_layout!`] macro instead declares a struct whose fields each take a
+//! given number of bits, and generates functions to pack it into a `Uuid`
+//! and unpack it again.
+//!
+//! The [`from_bits`] and [`to_bits`] functions used by the macro can also be
+//! used directly, to treat the free bits of a V8 UUID as a single integer.
+//!
+//! [`v8_layout!`]: ../macro.v8_layout.html
+//! [`from_bits`]: fn.from_bits.html
+//! [`to_bits`]: fn.to_bits.html
+
+use crate::prelude::*;
+
+/// The number of bits in a V8 UUID that aren't used by its version and
+/// variant.
+pub const FREE_BITS: u32 = 122;
+
+/// Creates a V8 UUID from the low 122 bits of `bits`.
+///
+/// The bits are stored in order, skipping over the version and variant bits.
+/// Any bits above the lowest 122 are ignored.
+///
+/// # Examples
+///
+/// ```
+/// use uuid::{layout, Version};
+///
+/// let uuid = layout::from_bits(1);
+///
+/// assert_eq!(uuid.get_version(), Some(Version::Custom));
+/// assert_eq!(
+///     uuid.to_hyphenated().to_string(),
+///     "00000000-0000-8000-8000-000000000001"
+/// );
+/// assert_eq!(layout::to_bits(&uuid), Some(1));
+/// ```
+pub const fn from_bits(bits: u128) -> Uuid {
+    let high = (bits >> 74) & 0xffff_ffff_ffff;
+    let mid = (bits >> 62) & 0xfff;
+    let low = bits & 0x3fff_ffff_ffff_ffff;
+
+    Uuid::from_u128(high << 80 | 0x8 << 76 | mid << 64 | 0b10 << 62 | low)
+}
+
+/// Returns the 122 free bits of a V8 UUID, or `None` if `uuid` isn't a V8
+/// UUID with the RFC4122 variant.
+///
+/// This is the inverse of [`from_bits`].
+///
+/// [`from_bits`]: fn.from_bits.html
+pub fn to_bits(uuid: &Uuid) -> Option<u128> {
+    if uuid.get_version() != Some(Version::Custom)
+        || uuid.get_variant() != Some(Variant::RFC4122)
+    {
+        return None;
+    }
+
+    let value = uuid.as_u128();
+
+    let high = (value >> 80) & 0xffff_ffff_ffff;
+    let mid = (value >> 64) & 0xfff;
+    let low = value & 0x3fff_ffff_ffff_ffff;
+
+    Some(high << 74 | mid << 62 | low)
+}
+
+/// Declares a struct that's packed into the free bits of a V8 UUID.
+///
+/// Each field is an unsigned integer with a number of bits given after `=`.
+/// Fields are packed in order, starting from the most significant free bit,
+/// so UUIDs sort by the first field, then the second, and so on. Bits left
+/// over after the last field are zero.
+///
+/// The struct gets two methods:
+///
+/// * `pack(&self) -> Uuid`, which panics if a field's value doesn't fit in
+///   its number of bits.
+/// * `unpack(&Uuid) -> Option<Self>`, which returns `None` if the UUID isn't
+///   a V8 UUID with the RFC4122 variant.
+///
+/// It also gets a `BITS` constant with the total number of bits used.
+///
+/// # Examples
+///
+/// ```
+/// use uuid::{v8_layout, Version};
+///
+/// v8_layout! {
+///     /// The ID of an order.
+///     #[derive(Debug, PartialEq)]
+///     pub struct OrderId {
+///         pub shard: u16 = 12,
+///         pub kind: u8 = 4,
+///         pub sequence: u64 = 64,
+///     }
+/// }
+///
+/// let id = OrderId {
+///     shard: 0xabc,
+///     kind: 0x3,
+///     sequence: 1,
+/// };
+/// let uuid = id.pack();
+///
+/// assert_eq!(uuid.get_version(), Some(Version::Custom));
+/// assert_eq!(
+///     uuid.to_hyphenated().to_string(),
+///     "abc30000-0000-8000-8000-040000000000"
+/// );
+/// assert_eq!(OrderId::unpack(&uuid), Some(id));
+/// assert_eq!(OrderId::BITS, 80);
+/// ```
+///
+/// Layouts that use more than the 122 free bits, fields that are wider than
+/// their type, or fields of signed types are compile errors:
+///
+/// ```compile_fail
+/// use uuid::v8_layout;
+///
+/// v8_layout! {
+///     struct TooWide {
+///         a: u64 = 64,
+///         b: u64 = 64,
+///     }
+/// }
+/// ```
+///
+/// ```compile_fail
+/// use uuid::v8_layout;
+///
+/// v8_layout! {
+///     struct TooNarrow {
+///         a: u8 = 9,
+///     }
+/// }
+/// ```
+///
+/// ```compile_fail
+/// use uuid::v8_layout;
+///
+/// v8_layout! {
+///     struct Signed {
+///         a: i8 = 8,
+///     }
+/// }
+/// ```
+#[macro_export]
+macro_rules! v8_layout {
+    (
+        $(#[$attr:meta])*
+        $vis:vis struct $name:ident {
+            $(
+                $(#[$field_attr:meta])*
+                $field_vis:vis $field:ident : $ty:ty = $bits:expr
+            ),* $(,)?
+        }
+    ) => {
+        $(#[$attr])*
+        $vis struct $name {
+            $(
+                $(#[$field_attr])*
+                $field_vis $field: $ty,
+            )*
+        }
+
+        const _: () = {
+            $(
+                assert!(
+                    <$ty>::MIN == 0,
+                    concat!(
+                        "field `",
+                        stringify!($field),
+                        "` must be an unsigned integer"
+                    )
+                );
+                assert!(
+                    $bits > 0 && $bits <= <$ty>::BITS,
+                    concat!(
+                        "field `",
+                        stringify!($field),
+                        "` must use between 1 and ",
+                        stringify!($ty),
+                        "::BITS bits"
+                    )
+                );
+            )*
+            assert!(
+                0 $(+ $bits)* <= $crate::layout::FREE_BITS,
+                concat!(
+                    "the fields of `",
+                    stringify!($name),
+                    "` use more than the 122 free bits of a V8 UUID"
+                )
+            );
+        };
+
+        impl $name {
+            /// The number of bits used by the fields.
+            #[allow(dead_code)]
+            pub const BITS: u32 = 0 $(+ $bits)*;
+
+            /// Packs the fields into a V8 UUID.
+            ///
+            /// # Panics
+            ///
+            /// Panics if the value of a field doesn't fit in its number of
+            /// bits.
+            #[allow(dead_code)]
+            pub fn pack(&self) -> $crate::Uuid {
+                let mut bits: u128 = 0;
+                $(
+                    let value = self.$field as u128;
+                    assert!(
+                        value >> ($bits) == 0,
+                        concat!(
+                            "value of `",
+                            stringify!($field),
+                            "` doesn't fit in its bits"
+                        )
+                    );
+                    bits = bits << ($bits) | value;
+                )*
+
+                $crate::layout::from_bits(
+                    bits << ($crate::layout::FREE_BITS - Self::BITS),
+                )
+            }
+
+            /// Unpacks the fields from a V8 UUID, or returns `None` if the
+            /// UUID isn't a V8 UUID with the RFC4122 variant.
+            #[allow(dead_code)]
+            pub fn unpack(uuid: &$crate::Uuid) -> Option<Self> {
+                let bits = $crate::layout::to_bits(uuid)?;
+                let mut offset = $crate::layout::FREE_BITS;
+                $(
+                    offset -= $bits;
+                    let $field =
+                        ((bits >> offset) & ((1u128 << ($bits)) - 1)) as $ty;
+                )*
+
+                Some($name { $($field,)* })
+            }
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::std::string::ToString;
+
+    v8_layout! {
+        #[derive(Debug, Clone, Copy, PartialEq)]
+        struct Full {
+            a: u64 = 48,
+            b: u16 = 12,
+            c: u64 = 62,
+        }
+    }
+
+    #[test]
+    fn test_bits_round_trip() {
+        let all = (1u128 << FREE_BITS) - 1;
+
+        assert_eq!(
+            from_bits(all).to_hyphenated().to_string(),
+            "ffffffff-ffff-8fff-bfff-ffffffffffff"
+        );
+        assert_eq!(from_bits(0).as_u128(), 0x8 << 76 | 0b10 << 62);
+
+        for &bits in &[0, 1, all, all >> 1, 0x0123_4567_89ab_cdef_u128 << 50] {
+            assert_eq!(to_bits(&from_bits(bits)), Some(bits));
+        }
+
+        assert_eq!(from_bits(u128::MAX), from_bits(all));
+    }
+
+    #[test]
+    fn test_to_bits_wrong_version() {
+        assert_eq!(to_bits(&Uuid::nil()), None);
+        assert_eq!(to_bits(&Uuid::NAMESPACE_DNS), None);
+
+        let microsoft =
+            Uuid::from_u128(from_bits(0).as_u128() | 1 << 63 | 1 << 62);
+        assert_eq!(to_bits(&microsoft), None);
+    }
+
+    #[test]
+    fn test_layout_full() {
+        let full = Full {
+            a: 0xffff_ffff_ffff,
+            b: 0,
+            c: 0x3fff_ffff_ffff_ffff,
+        };
+        let uuid = full.pack();
+
+        assert_eq!(Full::BITS, FREE_BITS);
+        assert_eq!(
+            uuid.to_hyphenated().to_string(),
+            "ffffffff-ffff-8000-bfff-ffffffffffff"
+        );
+        assert_eq!(Full::unpack(&uuid), Some(full));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_layout_value_too_wide() {
+        Full {
+            a: 1 << 48,
+            b: 0,
+            c: 0,
+        }
+        .pack();
+    }
+}
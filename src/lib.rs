@@ -33,15 +33,35 @@
 //!
 //! * `v1` - adds the [`Uuid::new_v1`] function and the ability to create a V1
 //!   using an implementation of [`v1::ClockSequence`] (usually
-//! [`v1::Context`]) and a timestamp from `time::timespec`.
+//!   [`v1::Context`]) and a timestamp from `time::timespec`.
 //! * `v3` - adds the [`Uuid::new_v3`] function and the ability to create a V3
 //!   UUID based on the MD5 hash of some data.
 //! * `v4` - adds the [`Uuid::new_v4`] function and the ability to randomly
 //!   generate a UUID.
 //! * `v5` - adds the [`Uuid::new_v5`] function and the ability to create a V5
-//!   UUID based on the SHA1 hash of some data.
+//!   UUID based on the SHA1 hash of some data. Together with `std`, it also
+//!   adds functions like [`Uuid::from_dns_name`] that normalize names in the
+//!   predefined namespaces before hashing them.
+//!   Together with `serde` and `std`, it also adds the
+//!   [`Uuid::new_v5_from_value`] function to hash a canonical encoding of
+//!   any serializable value.
+//! * `v7` - adds the [`Uuid::new_v7`] function and the ability to create a
+//!   time-ordered V7 UUID from a Unix timestamp and random bits.
+//! * `v8` - adds the [`Uuid::new_v8_sha256`] and [`Uuid::new_v8_sha512`]
+//!   functions and the ability to create a name-based V8 UUID based on a
+//!   SHA-2 hash of some data.
 //! * `serde` - adds the ability to serialize and deserialize a UUID using the
 //!   `serde` crate.
+//! * `digest` - adds the [`Uuid::new_name_based`] function and the ability to
+//!   create a name-based UUID using any hash implementing the RustCrypto
+//!   [`digest`] traits.
+//! * `keyed` - adds the [`Uuid::new_keyed`] function and the ability to create
+//!   a V8 UUID based on the HMAC-SHA-256 of some data under a secret key.
+//! * `v3-digest` and `v5-digest` - like `v3` and `v5`, but compute the hashes
+//!   with the RustCrypto [`md-5`] and [`sha1`] crates instead.
+//! * `macros` - adds the [`uuid!`] macro to parse UUID literals at compile
+//!   time and the [`new_v4_const!`] macro to generate a random UUID at
+//!   compile time.
 //!
 //! For WebAssembly, enable one of the following features depending
 //! on your JavaScript interop toolchain of choice:
@@ -108,20 +128,34 @@
 //! * simple: `936DA01F9ABD4d9d80C702AF85C822A8`
 //! * hyphenated: `550e8400-e29b-41d4-a716-446655440000`
 //! * urn: `urn:uuid:F9168C5E-CEB2-4faa-B6BF-329BF39FA1E4`
+//! * braced: `{F9168C5E-CEB2-4faa-B6BF-329BF39FA1E4}`
 //!
 //! # References
 //!
 //! * [Wikipedia: Universally Unique Identifier](http://en.wikipedia.org/wiki/Universally_unique_identifier)
 //! * [RFC4122: A Universally Unique IDentifier (UUID) URN Namespace](http://tools.ietf.org/html/rfc4122)
+//! * [RFC9562: Universally Unique IDentifiers (UUIDs)](https://www.rfc-editor.org/rfc/rfc9562)
 //!
 //! [`wasm-bindgen`]: https://crates.io/crates/wasm-bindgen
 //! [`cargo-web`]: https://crates.io/crates/cargo-web
 //! [`stdweb`]: https://crates.io/crates/stdweb
 //! [`Uuid`]: struct.Uuid.html
+//! [`digest`]: https://crates.io/crates/digest
+//! [`uuid!`]: macro.uuid.html
+//! [`new_v4_const!`]: macro.new_v4_const.html
+//! [`md-5`]: https://crates.io/crates/md-5
+//! [`sha1`]: https://crates.io/crates/sha1
+//! [`Uuid::from_dns_name`]: struct.Uuid.html#method.from_dns_name
+//! [`Uuid::new_v5_from_value`]: struct.Uuid.html#method.new_v5_from_value
+//! [`Uuid::new_keyed`]: struct.Uuid.html#method.new_keyed
+//! [`Uuid::new_name_based`]: struct.Uuid.html#method.new_name_based
 //! [`Uuid::new_v1`]: struct.Uuid.html#method.new_v1
 //! [`Uuid::new_v3`]: struct.Uuid.html#method.new_v3
 //! [`Uuid::new_v4`]: struct.Uuid.html#method.new_v4
 //! [`Uuid::new_v5`]: struct.Uuid.html#method.new_v5
+//! [`Uuid::new_v7`]: struct.Uuid.html#method.new_v7
+//! [`Uuid::new_v8_sha256`]: struct.Uuid.html#method.new_v8_sha256
+//! [`Uuid::new_v8_sha512`]: struct.Uuid.html#method.new_v8_sha512
 //! [`v1::ClockSequence`]: v1/trait.ClockSequence.html
 //! [`v1::Context`]: v1/struct.Context.html
 
@@ -142,32 +176,118 @@ extern crate std;
 extern crate core as std;
 
 mod builder;
+#[cfg(all(
+    feature = "serde",
+    feature = "std",
+    any(feature = "v5", feature = "v5-digest")
+))]
+mod canonical;
 mod error;
+#[cfg(all(feature = "std", any(feature = "v5", feature = "v5-digest")))]
+mod name;
 mod parser;
 mod prelude;
 
 pub mod adapter;
+pub mod generator;
+#[cfg(feature = "std")]
+pub mod io;
+pub mod layout;
+pub mod namespace;
 #[cfg(feature = "v1")]
 pub mod v1;
 
+#[cfg(feature = "digest")]
+mod digest_support;
+#[cfg(feature = "keyed")]
+mod keyed;
 #[cfg(feature = "serde")]
 mod serde_support;
 #[cfg(feature = "slog")]
 mod slog_support;
 #[cfg(test)]
 mod test_util;
-#[cfg(feature = "v3")]
+#[cfg(any(feature = "v3", feature = "v3-digest"))]
 mod v3;
 #[cfg(feature = "v4")]
 mod v4;
-#[cfg(feature = "v5")]
+#[cfg(any(feature = "v5", feature = "v5-digest"))]
 mod v5;
+#[cfg(feature = "v7")]
+mod v7;
+#[cfg(feature = "v8")]
+mod v8;
 #[cfg(all(windows, feature = "winapi"))]
 mod winapi_support;
 
 use crate::std::{convert, fmt, str};
 
-pub use crate::error::Error;
+pub use crate::error::{Error, ErrorKind};
+#[cfg(feature = "std")]
+pub use crate::parser::replace_all;
+pub use crate::parser::{find_iter, Diagnostic, FindIter, ParseOptions};
+
+/// Parses a [`Uuid`] from a string literal at compile time.
+///
+/// The literal can be in any format accepted by [`Uuid::parse_str`]. The
+/// macro parses it with [`Uuid::parse_str_const`] in a constant, so it can
+/// initialize a `const` and an invalid literal is always a compile error,
+/// reported at the literal with the message [`Uuid::parse_str`] would
+/// return.
+///
+/// Note that usage of this macro requires the `macros` feature of this crate
+/// to be enabled, and that the crate is available as `uuid`.
+///
+/// # Examples
+///
+/// ```
+/// use uuid::{uuid, Uuid};
+///
+/// const ID: Uuid = uuid!("67e55044-10b1-426f-9247-bb680e5fe0c8");
+///
+/// assert_eq!(
+///     ID,
+///     Uuid::parse_str("67e55044-10b1-426f-9247-bb680e5fe0c8").unwrap()
+/// );
+/// ```
+///
+/// An invalid literal doesn't compile:
+///
+/// ```compile_fail
+/// use uuid::{uuid, Uuid};
+///
+/// const ID: Uuid = uuid!("67e55044-10b1-426f-9247-bb680e5fe0cX");
+/// ```
+///
+/// [`Uuid`]: struct.Uuid.html
+/// [`Uuid::parse_str`]: struct.Uuid.html#method.parse_str
+/// [`Uuid::parse_str_const`]: struct.Uuid.html#method.parse_str_const
+#[cfg(feature = "macros")]
+pub use uuid_macro_internal::uuid;
+
+/// Generates a random (V4) [`Uuid`] at compile time.
+///
+/// Each use of the macro expands to a different UUID, which stays the same
+/// until the code is compiled again. This is useful for IDs that must be
+/// fixed and unique per declaration, such as interface IDs, without copying
+/// them from elsewhere.
+///
+/// Note that usage of this macro requires the `macros` feature of this crate
+/// to be enabled, and that the crate is available as `uuid`.
+///
+/// # Examples
+///
+/// ```
+/// use uuid::{new_v4_const, Uuid, Version};
+///
+/// const PLUGIN_ID: Uuid = new_v4_const!();
+///
+/// assert_eq!(PLUGIN_ID.get_version(), Some(Version::Random));
+/// ```
+///
+/// [`Uuid`]: struct.Uuid.html
+#[cfg(feature = "macros")]
+pub use uuid_macro_internal::new_v4_const;
 
 /// A builder struct for creating a UUID.
 ///
@@ -196,7 +316,11 @@ pub struct Builder(Bytes);
 pub type Bytes = [u8; 16];
 
 /// The version of the UUID, denoting the generating algorithm.
+///
+/// More versions may be added in the future, so matching on a `Version`
+/// needs a wildcard arm.
 #[derive(Clone, Copy, Debug, PartialEq)]
+#[non_exhaustive]
 pub enum Version {
     /// Special case for `nil` UUID.
     Nil = 0,
@@ -210,6 +334,10 @@ pub enum Version {
     Random,
     /// Version 5: SHA-1 hash.
     Sha1,
+    /// Version 7: Unix timestamp and random bits.
+    SortRand = 7,
+    /// Version 8: Custom.
+    Custom = 8,
 }
 
 /// The reserved variants of UUIDs.
@@ -298,6 +426,8 @@ impl Uuid {
             3 => Some(Version::Md5),
             4 => Some(Version::Random),
             5 => Some(Version::Sha1),
+            7 => Some(Version::SortRand),
+            8 => Some(Version::Custom),
             _ => None,
         }
     }
@@ -519,7 +649,9 @@ impl Uuid {
     }
 
     /// A buffer that can be used for `encode_...` calls, that is
-    /// guaranteed to be long enough for any of the adapters.
+    /// guaranteed to be long enough for any of the adapters except
+    /// [`Initializer`] and [`InitializerRef`], which need a buffer of
+    /// [`Initializer::LENGTH`].
     ///
     /// # Examples
     ///
@@ -544,6 +676,10 @@ impl Uuid {
     ///     "urn:uuid:00000000-0000-0000-0000-000000000000"
     /// );
     /// ```
+    ///
+    /// [`Initializer`]: adapter/struct.Initializer.html
+    /// [`InitializerRef`]: adapter/struct.InitializerRef.html
+    /// [`Initializer::LENGTH`]: adapter/struct.Initializer.html#associatedconstant.LENGTH
     pub const fn encode_buffer() -> [u8; adapter::Urn::LENGTH] {
         [0; adapter::Urn::LENGTH]
     }
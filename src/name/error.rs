@@ -0,0 +1,80 @@
+use crate::std::fmt;
+
+/// An error that can occur while normalizing a name before hashing it into
+/// a [`Uuid`].
+///
+/// [`Uuid`]: ../struct.Uuid.html
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub(crate) enum Error {
+    /// The name is empty.
+    Empty,
+    /// A DNS name contains an empty label.
+    EmptyLabel {
+        /// The position of the empty label.
+        index: usize,
+    },
+    /// A DNS name contains a label that is longer than 63 bytes.
+    LabelTooLong {
+        /// The position of the label.
+        index: usize,
+        /// The length of the label found.
+        found: usize,
+    },
+    /// A URL doesn't start with a valid scheme.
+    InvalidScheme,
+    /// A URL contains a `%` that isn't followed by two hexadecimal digits.
+    InvalidPercentEncoding {
+        /// The position of the `%`.
+        index: usize,
+    },
+    /// An OID contains an arc that isn't a valid number, or doesn't have
+    /// enough arcs.
+    InvalidOidArc {
+        /// The (0-indexed) number of the invalid arc.
+        arc: usize,
+    },
+    /// An X.500 DN contains an attribute that isn't of the form
+    /// `type=value`.
+    InvalidAttribute {
+        /// The (0-indexed) number of the relative DN with the attribute.
+        rdn: usize,
+    },
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            Error::Empty => write!(f, "invalid name: the name is empty"),
+            Error::EmptyLabel { index } => {
+                write!(f, "invalid DNS name: empty label at {}", index)
+            }
+            Error::LabelTooLong { index, found } => write!(
+                f,
+                "invalid DNS name: expected at most 63, found {} in label at {}",
+                found, index
+            ),
+            Error::InvalidScheme => {
+                write!(f, "invalid URL: missing or invalid scheme")
+            }
+            Error::InvalidPercentEncoding { index } => {
+                write!(f, "invalid URL: invalid percent-encoding at {}", index)
+            }
+            Error::InvalidOidArc { arc } => {
+                write!(f, "invalid OID: invalid arc {}", arc)
+            }
+            Error::InvalidAttribute { rdn } => write!(
+                f,
+                "invalid X.500 DN: expected `type=value` in RDN {}",
+                rdn
+            ),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+mod std_support {
+    use super::*;
+    use crate::std::error;
+
+    impl error::Error for Error {}
+}
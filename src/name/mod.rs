@@ -0,0 +1,619 @@
+// Copyright 2013-2014 The Rust Project Developers.
+// Copyright 2018 The Uuid Project Developers.
+//
+// See the COPYRIGHT file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Normalization of names in the predefined [`Uuid`] namespaces.
+//!
+//! [`Uuid`]: ../struct.Uuid.html
+
+mod error;
+pub(crate) use self::error::Error;
+
+use crate::prelude::*;
+use crate::std::{ops::Range, string::String};
+
+impl Uuid {
+    /// Creates a version 5 UUID from a DNS name in [`NAMESPACE_DNS`],
+    /// normalizing the name first.
+    ///
+    /// The name is normalized by:
+    ///
+    /// * removing a single trailing `.`, which marks a fully qualified name,
+    /// * folding ASCII letters to lower case.
+    ///
+    /// Internationalized names should be converted to their ASCII (punycode)
+    /// form beforehand; other characters are hashed as they are.
+    ///
+    /// Note that usage of this method requires the `v5` and `std` features of
+    /// this crate to be enabled.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the name is empty, contains an empty label or
+    /// contains a label that is longer than 63 bytes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use uuid::Uuid;
+    ///
+    /// fn main() -> Result<(), uuid::Error> {
+    ///     assert_eq!(
+    ///         Uuid::from_dns_name("Example.COM.")?,
+    ///         Uuid::new_v5(&Uuid::NAMESPACE_DNS, b"example.com"),
+    ///     );
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    /// [`NAMESPACE_DNS`]: struct.Uuid.html#associatedconst.NAMESPACE_DNS
+    pub fn from_dns_name(name: &str) -> Result<Uuid, crate::Error> {
+        let name = normalize_dns_name(name)?;
+
+        Ok(Uuid::new_v5(&Uuid::NAMESPACE_DNS, name.as_bytes()))
+    }
+
+    /// Creates a version 5 UUID from a URL in [`NAMESPACE_URL`],
+    /// normalizing the URL first.
+    ///
+    /// The URL is normalized following [RFC 3986 Section 6.2.2][RFC] by:
+    ///
+    /// * folding the scheme and host to lower case,
+    /// * writing the hexadecimal digits of percent-encoded octets in upper
+    ///   case,
+    /// * decoding percent-encoded unreserved characters (`A-Z`, `a-z`, `0-9`,
+    ///   `-`, `.`, `_` and `~`).
+    ///
+    /// For the `http` and `https` schemes, an empty path is also replaced
+    /// with `/` and the default port is removed.
+    ///
+    /// Note that usage of this method requires the `v5` and `std` features of
+    /// this crate to be enabled.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the URL doesn't start with a scheme or contains a
+    /// `%` that isn't followed by two hexadecimal digits.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use uuid::Uuid;
+    ///
+    /// fn main() -> Result<(), uuid::Error> {
+    ///     assert_eq!(
+    ///         Uuid::from_url("HTTP://Example.com:80")?,
+    ///         Uuid::new_v5(&Uuid::NAMESPACE_URL, b"http://example.com/"),
+    ///     );
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    /// [RFC]: https://tools.ietf.org/html/rfc3986#section-6.2.2
+    /// [`NAMESPACE_URL`]: struct.Uuid.html#associatedconst.NAMESPACE_URL
+    pub fn from_url(url: &str) -> Result<Uuid, crate::Error> {
+        let url = normalize_url(url)?;
+
+        Ok(Uuid::new_v5(&Uuid::NAMESPACE_URL, url.as_bytes()))
+    }
+
+    /// Creates a version 5 UUID from an ISO Object Identifier in
+    /// [`NAMESPACE_OID`], normalizing the OID first.
+    ///
+    /// The OID must be in dotted decimal form, optionally prefixed by
+    /// `urn:oid:`. It is normalized by removing the prefix and any leading
+    /// zeros from its arcs.
+    ///
+    /// Note that usage of this method requires the `v5` and `std` features of
+    /// this crate to be enabled.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the OID has fewer than two arcs, has an arc that
+    /// isn't a decimal number, has a first arc other than `0`, `1` or `2`, or
+    /// has a second arc greater than `39` below the `0` or `1` arcs.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use uuid::Uuid;
+    ///
+    /// fn main() -> Result<(), uuid::Error> {
+    ///     assert_eq!(
+    ///         Uuid::from_oid("urn:oid:1.3.06.1")?,
+    ///         Uuid::new_v5(&Uuid::NAMESPACE_OID, b"1.3.6.1"),
+    ///     );
+    ///
+    ///     assert!(Uuid::from_oid("1.40.1").is_err());
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    /// [`NAMESPACE_OID`]: struct.Uuid.html#associatedconst.NAMESPACE_OID
+    pub fn from_oid(oid: &str) -> Result<Uuid, crate::Error> {
+        let oid = normalize_oid(oid)?;
+
+        Ok(Uuid::new_v5(&Uuid::NAMESPACE_OID, oid.as_bytes()))
+    }
+
+    /// Creates a version 5 UUID from an X.500 Distinguished Name in
+    /// [`NAMESPACE_X500`], normalizing the DN first.
+    ///
+    /// The DN is expected in the string form of [RFC 4514][RFC]. It is
+    /// normalized by:
+    ///
+    /// * removing unescaped spaces around attribute types, values and the
+    ///   `,`, `+` and `=` separators,
+    /// * folding ASCII letters in attribute types and values to lower case,
+    ///   as the common attribute types are matched case-insensitively.
+    ///
+    /// Escaped characters are kept as they are, so an escaped `,` doesn't
+    /// start a new relative DN.
+    ///
+    /// Note that usage of this method requires the `v5` and `std` features of
+    /// this crate to be enabled.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if an attribute isn't of the form `type=value`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use uuid::Uuid;
+    ///
+    /// fn main() -> Result<(), uuid::Error> {
+    ///     assert_eq!(
+    ///         Uuid::from_x500_dn("CN=Jane Doe, O=Example")?,
+    ///         Uuid::new_v5(&Uuid::NAMESPACE_X500, b"cn=jane doe,o=example"),
+    ///     );
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    /// [RFC]: https://tools.ietf.org/html/rfc4514
+    /// [`NAMESPACE_X500`]: struct.Uuid.html#associatedconst.NAMESPACE_X500
+    pub fn from_x500_dn(dn: &str) -> Result<Uuid, crate::Error> {
+        let dn = normalize_x500_dn(dn)?;
+
+        Ok(Uuid::new_v5(&Uuid::NAMESPACE_X500, dn.as_bytes()))
+    }
+}
+
+/// The longest a single DNS label can be.
+const MAX_LABEL_LEN: usize = 63;
+
+fn normalize_dns_name(name: &str) -> Result<String, Error> {
+    let name = name.strip_suffix('.').unwrap_or(name);
+
+    if name.is_empty() {
+        return Err(Error::Empty);
+    }
+
+    let mut index = 0;
+    for label in name.split('.') {
+        if label.is_empty() {
+            return Err(Error::EmptyLabel { index });
+        }
+
+        if label.len() > MAX_LABEL_LEN {
+            return Err(Error::LabelTooLong {
+                index,
+                found: label.len(),
+            });
+        }
+
+        index += label.len() + 1;
+    }
+
+    Ok(name.to_ascii_lowercase())
+}
+
+fn normalize_url(url: &str) -> Result<String, Error> {
+    let scheme_len = url.find(':').ok_or(Error::InvalidScheme)?;
+    let scheme = &url[..scheme_len];
+
+    let mut chars = scheme.chars();
+    let valid_scheme = chars.next().map_or(false, |c| c.is_ascii_alphabetic())
+        && chars.all(|c| {
+            c.is_ascii_alphanumeric() || c == '+' || c == '-' || c == '.'
+        });
+
+    if !valid_scheme {
+        return Err(Error::InvalidScheme);
+    }
+
+    // Fold the case of the scheme and host before normalizing the
+    // percent-encodings, so the hex digits of an encoded octet in the host
+    // stay upper case. ASCII case folding keeps every byte in place, so
+    // error indexes are still relative to the original `url`.
+    let mut url = String::from(url);
+    url[..scheme_len].make_ascii_lowercase();
+    if let Some(host) = host_range(&url, scheme_len + 1) {
+        url[host].make_ascii_lowercase();
+    }
+
+    let scheme = &url[..scheme_len];
+    let rest = normalize_percent_encoding(&url, scheme_len + 1)?;
+
+    let mut normalized = String::with_capacity(url.len() + 1);
+    normalized.push_str(scheme);
+    normalized.push(':');
+
+    let rest = if let Some(hierarchy) = rest.strip_prefix("//") {
+        let authority_len = hierarchy
+            .find(&['/', '?', '#'][..])
+            .unwrap_or(hierarchy.len());
+        let (authority, path) = hierarchy.split_at(authority_len);
+
+        let host_start = authority.rfind('@').map_or(0, |at| at + 1);
+        let mut host = String::from(&authority[host_start..]);
+
+        let is_http = scheme == "http" || scheme == "https";
+        let default_port = if scheme == "http" { ":80" } else { ":443" };
+        if is_http && host.ends_with(default_port) {
+            host.truncate(host.len() - default_port.len());
+        }
+
+        normalized.push_str("//");
+        normalized.push_str(&authority[..host_start]);
+        normalized.push_str(&host);
+
+        if is_http && !path.starts_with('/') {
+            normalized.push('/');
+        }
+
+        String::from(path)
+    } else {
+        rest
+    };
+
+    normalized.push_str(&rest);
+
+    Ok(normalized)
+}
+
+/// The byte range of the host in the authority of `url[start..]`, if it has
+/// one.
+///
+/// Only the host is case-insensitive, the user info isn't.
+fn host_range(url: &str, start: usize) -> Option<Range<usize>> {
+    let hierarchy = url[start..].strip_prefix("//")?;
+    let authority_len = hierarchy
+        .find(&['/', '?', '#'][..])
+        .unwrap_or(hierarchy.len());
+    let host_start =
+        hierarchy[..authority_len].rfind('@').map_or(0, |at| at + 1);
+
+    Some(start + 2 + host_start..start + 2 + authority_len)
+}
+
+/// Normalizes the percent-encoded octets in `url[start..]`.
+///
+/// Indexes in errors are relative to the start of `url`.
+fn normalize_percent_encoding(
+    url: &str,
+    start: usize,
+) -> Result<String, Error> {
+    fn hex_value(b: u8) -> Option<u8> {
+        match b {
+            b'0'..=b'9' => Some(b - b'0'),
+            b'a'..=b'f' => Some(b - b'a' + 10),
+            b'A'..=b'F' => Some(b - b'A' + 10),
+            _ => None,
+        }
+    }
+
+    let bytes = url.as_bytes();
+    let mut normalized = String::with_capacity(url.len() - start);
+
+    let mut last = start;
+    let mut index = start;
+    while index < bytes.len() {
+        if bytes[index] != b'%' {
+            index += 1;
+            continue;
+        }
+
+        let value = match (
+            bytes.get(index + 1).and_then(|&b| hex_value(b)),
+            bytes.get(index + 2).and_then(|&b| hex_value(b)),
+        ) {
+            (Some(hi), Some(lo)) => hi * 16 + lo,
+            _ => return Err(Error::InvalidPercentEncoding { index }),
+        };
+
+        normalized.push_str(&url[last..index]);
+
+        let c = value as char;
+        if c.is_ascii_alphanumeric() || "-._~".contains(c) {
+            normalized.push(c);
+        } else {
+            normalized.push('%');
+            normalized
+                .push_str(&url[index + 1..index + 3].to_ascii_uppercase());
+        }
+
+        index += 3;
+        last = index;
+    }
+
+    normalized.push_str(&url[last..]);
+
+    Ok(normalized)
+}
+
+fn normalize_oid(oid: &str) -> Result<String, Error> {
+    const PREFIX: &str = "urn:oid:";
+
+    let oid = if oid.len() >= PREFIX.len()
+        && oid.is_char_boundary(PREFIX.len())
+        && oid[..PREFIX.len()].eq_ignore_ascii_case(PREFIX)
+    {
+        &oid[PREFIX.len()..]
+    } else {
+        oid
+    };
+
+    let mut normalized = String::with_capacity(oid.len());
+
+    let mut arcs = 0;
+    for (arc, digits) in oid.split('.').enumerate() {
+        if digits.is_empty() || !digits.bytes().all(|b| b.is_ascii_digit()) {
+            return Err(Error::InvalidOidArc { arc });
+        }
+
+        let digits = digits.trim_start_matches('0');
+        let digits = if digits.is_empty() { "0" } else { digits };
+
+        let valid = match arc {
+            // The first arc is one of `itu-t(0)`, `iso(1)` or
+            // `joint-iso-itu-t(2)`.
+            0 => digits.len() == 1 && digits <= "2",
+            // Below `itu-t(0)` and `iso(1)` there are at most 40 arcs.
+            1 if normalized.as_str() != "2" => {
+                digits.len() == 1 || (digits.len() == 2 && digits <= "39")
+            }
+            _ => true,
+        };
+
+        if !valid {
+            return Err(Error::InvalidOidArc { arc });
+        }
+
+        if arc > 0 {
+            normalized.push('.');
+        }
+        normalized.push_str(digits);
+
+        arcs += 1;
+    }
+
+    if arcs < 2 {
+        return Err(Error::InvalidOidArc { arc: arcs });
+    }
+
+    Ok(normalized)
+}
+
+fn normalize_x500_dn(dn: &str) -> Result<String, Error> {
+    let mut normalized = String::with_capacity(dn.len());
+
+    let dn = trim_unescaped(dn);
+    if dn.is_empty() {
+        return Ok(normalized);
+    }
+
+    for (rdn, relative) in split_unescaped(dn, ',').enumerate() {
+        if rdn > 0 {
+            normalized.push(',');
+        }
+
+        for (i, attribute) in split_unescaped(relative, '+').enumerate() {
+            if i > 0 {
+                normalized.push('+');
+            }
+
+            let mut parts = split_unescaped(attribute, '=');
+            let raw_type = parts.next().unwrap_or("");
+            let attribute_type = trim_unescaped(raw_type);
+
+            if parts.next().is_none() || attribute_type.is_empty() {
+                return Err(Error::InvalidAttribute { rdn });
+            }
+
+            // Everything after the first `=` is the value.
+            let value = trim_unescaped(&attribute[raw_type.len() + 1..]);
+
+            normalized.push_str(&attribute_type.to_ascii_lowercase());
+            normalized.push('=');
+            normalized.push_str(&value.to_ascii_lowercase());
+        }
+    }
+
+    Ok(normalized)
+}
+
+/// Splits `s` at each occurrence of `sep` that isn't escaped by a `\`.
+fn split_unescaped(s: &str, sep: char) -> impl Iterator<Item = &str> {
+    let mut escaped = false;
+
+    s.split(move |c| {
+        let split = !escaped && c == sep;
+        escaped = !escaped && c == '\\';
+
+        split
+    })
+}
+
+/// Removes leading and trailing spaces from `s`, keeping a trailing space
+/// that is escaped by a `\`.
+fn trim_unescaped(s: &str) -> &str {
+    let s = s.trim_start_matches(' ');
+    let trimmed = s.trim_end_matches(' ');
+
+    let backslashes = trimmed.len() - trimmed.trim_end_matches('\\').len();
+
+    if backslashes % 2 == 1 {
+        // The first trailing space was escaped, keep it.
+        &s[..trimmed.len() + 1]
+    } else {
+        trimmed
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_dns_name() {
+        assert_eq!(
+            normalize_dns_name("Example.COM."),
+            Ok("example.com".into())
+        );
+        assert_eq!(normalize_dns_name("example.com"), Ok("example.com".into()));
+        assert_eq!(normalize_dns_name("localhost"), Ok("localhost".into()));
+
+        assert_eq!(normalize_dns_name(""), Err(Error::Empty));
+        assert_eq!(normalize_dns_name("."), Err(Error::Empty));
+        assert_eq!(
+            normalize_dns_name("example..com"),
+            Err(Error::EmptyLabel { index: 8 })
+        );
+        assert_eq!(
+            normalize_dns_name(".example.com"),
+            Err(Error::EmptyLabel { index: 0 })
+        );
+        assert_eq!(
+            normalize_dns_name(&format!("www.{}.com", "a".repeat(64))),
+            Err(Error::LabelTooLong {
+                index: 4,
+                found: 64
+            })
+        );
+
+        assert_eq!(
+            Uuid::from_dns_name("Rust-Lang.ORG.").unwrap(),
+            Uuid::new_v5(&Uuid::NAMESPACE_DNS, b"rust-lang.org")
+        );
+    }
+
+    #[test]
+    fn test_url() {
+        assert_eq!(
+            normalize_url("HTTP://Example.COM"),
+            Ok("http://example.com/".into())
+        );
+        assert_eq!(
+            normalize_url("https://User@Example.COM:443/A%2fb%7e%41?Q#F"),
+            Ok("https://User@example.com/A%2Fb~A?Q#F".into())
+        );
+        assert_eq!(
+            normalize_url("http://Ex%2fample.COM/%2f"),
+            Ok("http://ex%2Fample.com/%2F".into())
+        );
+        assert_eq!(
+            normalize_url("http://User%2f@Ex%c3%a9.COM"),
+            Ok("http://User%2F@ex%C3%A9.com/".into())
+        );
+        assert_eq!(
+            normalize_url("http://example.com:8080?q"),
+            Ok("http://example.com:8080/?q".into())
+        );
+        assert_eq!(
+            normalize_url("ftp://Example.COM"),
+            Ok("ftp://example.com".into())
+        );
+        assert_eq!(
+            normalize_url("MAILTO:User@Example.COM"),
+            Ok("mailto:User@Example.COM".into())
+        );
+
+        assert_eq!(normalize_url("example.com"), Err(Error::InvalidScheme));
+        assert_eq!(normalize_url(":example"), Err(Error::InvalidScheme));
+        assert_eq!(normalize_url("1http://a"), Err(Error::InvalidScheme));
+        assert_eq!(
+            normalize_url("http://example.com/%4"),
+            Err(Error::InvalidPercentEncoding { index: 19 })
+        );
+        assert_eq!(
+            normalize_url("http://example.com/%zz"),
+            Err(Error::InvalidPercentEncoding { index: 19 })
+        );
+
+        assert_eq!(
+            Uuid::from_url("HTTP://Rust-Lang.org").unwrap(),
+            Uuid::new_v5(&Uuid::NAMESPACE_URL, b"http://rust-lang.org/")
+        );
+    }
+
+    #[test]
+    fn test_oid() {
+        assert_eq!(normalize_oid("1.3.6.1"), Ok("1.3.6.1".into()));
+        assert_eq!(normalize_oid("URN:OID:1.3.6.1"), Ok("1.3.6.1".into()));
+        assert_eq!(normalize_oid("01.003.6.0"), Ok("1.3.6.0".into()));
+        assert_eq!(normalize_oid("0.39"), Ok("0.39".into()));
+        assert_eq!(normalize_oid("2.999.1"), Ok("2.999.1".into()));
+
+        assert_eq!(normalize_oid(""), Err(Error::InvalidOidArc { arc: 0 }));
+        assert_eq!(normalize_oid("1"), Err(Error::InvalidOidArc { arc: 1 }));
+        assert_eq!(normalize_oid("3.1"), Err(Error::InvalidOidArc { arc: 0 }));
+        assert_eq!(normalize_oid("1.40"), Err(Error::InvalidOidArc { arc: 1 }));
+        assert_eq!(
+            normalize_oid("1.3..1"),
+            Err(Error::InvalidOidArc { arc: 2 })
+        );
+        assert_eq!(
+            normalize_oid("1.3.6.-1"),
+            Err(Error::InvalidOidArc { arc: 3 })
+        );
+
+        assert_eq!(
+            Uuid::from_oid("1.3.06.1").unwrap(),
+            Uuid::new_v5(&Uuid::NAMESPACE_OID, b"1.3.6.1")
+        );
+    }
+
+    #[test]
+    fn test_x500_dn() {
+        assert_eq!(
+            normalize_x500_dn(" CN = Jane Doe , O=Example+OU=Dev "),
+            Ok("cn=jane doe,o=example+ou=dev".into())
+        );
+        assert_eq!(
+            normalize_x500_dn("CN=Doe\\, Jane,O=Example"),
+            Ok("cn=doe\\, jane,o=example".into())
+        );
+        assert_eq!(
+            normalize_x500_dn("CN=Trailing\\ ,O=a=b"),
+            Ok("cn=trailing\\ ,o=a=b".into())
+        );
+        assert_eq!(normalize_x500_dn(""), Ok("".into()));
+
+        assert_eq!(
+            normalize_x500_dn("CN=Jane,Example"),
+            Err(Error::InvalidAttribute { rdn: 1 })
+        );
+        assert_eq!(
+            normalize_x500_dn("=Jane"),
+            Err(Error::InvalidAttribute { rdn: 0 })
+        );
+
+        assert_eq!(
+            Uuid::from_x500_dn("CN=Jane Doe, O=Example").unwrap(),
+            Uuid::new_v5(&Uuid::NAMESPACE_X500, b"cn=jane doe,o=example")
+        );
+    }
+}
@@ -0,0 +1,163 @@
+// Copyright 2013-2014 The Rust Project Developers.
+// Copyright 2018 The Uuid Project Developers.
+//
+// See the COPYRIGHT file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Well-known namespaces for name-based [`Uuid`]s.
+//!
+//! This module gathers the namespaces defined in [RFC 4122 Appendix C][RFC],
+//! along with other namespaces in wide use. Other namespaces can be declared
+//! as constants with the [`uuid_namespace!`] macro, which checks the UUID
+//! literal at compile time.
+//!
+//! # Examples
+//!
+//! ```
+//! use uuid::{namespace, uuid_namespace, Uuid};
+//!
+//! uuid_namespace! {
+//!     /// The namespace for our tenant IDs.
+//!     pub TENANTS = "9a1e4f9a-3c1b-4c4d-8a53-8bd1a2e0c7a2"
+//! }
+//!
+//! assert_eq!(namespace::DNS, Uuid::NAMESPACE_DNS);
+//! assert_eq!(
+//!     TENANTS,
+//!     Uuid::parse_str("9a1e4f9a-3c1b-4c4d-8a53-8bd1a2e0c7a2").unwrap()
+//! );
+//! ```
+//!
+//! [RFC]: https://tools.ietf.org/html/rfc4122#appendix-C
+//! [`Uuid`]: ../struct.Uuid.html
+//! [`uuid_namespace!`]: ../macro.uuid_namespace.html
+
+use crate::prelude::*;
+
+/// UUID namespace for Domain Name System (DNS).
+pub const DNS: Uuid = Uuid::NAMESPACE_DNS;
+
+/// UUID namespace for ISO Object Identifiers (OIDs).
+pub const OID: Uuid = Uuid::NAMESPACE_OID;
+
+/// UUID namespace for Uniform Resource Locators (URLs).
+pub const URL: Uuid = Uuid::NAMESPACE_URL;
+
+/// UUID namespace for X.500 Distinguished Names (DNs).
+pub const X500: Uuid = Uuid::NAMESPACE_X500;
+
+/// UUID namespace for Event Tracing for Windows (ETW) provider names.
+///
+/// This is the namespace .NET's `EventSource` and TraceLogging use to derive
+/// the GUID of a provider from its name. Note that they hash the upper-cased
+/// name encoded as UTF-16 with their own variant of the version 5 algorithm,
+/// so [`Uuid::new_v5`] with this namespace doesn't reproduce their GUIDs.
+///
+/// [`Uuid::new_v5`]: ../struct.Uuid.html#method.new_v5
+pub const ETW: Uuid =
+    Uuid::parse_str_const("482c2db2-c390-47c8-87f8-1a15bfc130fb");
+
+/// All of the namespaces in this module, along with their names.
+pub const ALL: [(&str, Uuid); 5] = [
+    ("DNS", DNS),
+    ("OID", OID),
+    ("URL", URL),
+    ("X500", X500),
+    ("ETW", ETW),
+];
+
+/// Declares a namespace as a `const` [`Uuid`].
+///
+/// The UUID is given as a string literal in any format accepted by
+/// [`Uuid::parse_str_const`], and is checked at compile time, so an invalid
+/// literal fails to compile rather than failing at runtime.
+///
+/// # Examples
+///
+/// ```
+/// use uuid::{uuid_namespace, Uuid};
+///
+/// uuid_namespace!(PROJECTS = "67e55044-10b1-426f-9247-bb680e5fe0c8");
+///
+/// assert_eq!(PROJECTS.get_version(), Some(uuid::Version::Random));
+/// ```
+///
+/// Attributes such as doc comments and a visibility can be given before the
+/// name:
+///
+/// ```
+/// use uuid::uuid_namespace;
+///
+/// uuid_namespace! {
+///     /// The namespace for our tenant IDs.
+///     pub(crate) TENANTS = "9a1e4f9a3c1b4c4d8a538bd1a2e0c7a2"
+/// }
+/// ```
+///
+/// An invalid literal is a compile error:
+///
+/// ```compile_fail
+/// use uuid::uuid_namespace;
+///
+/// uuid_namespace!(PROJECTS = "67e55044-10b1-426f-9247-bb680e5fe0cX");
+/// ```
+///
+/// [`Uuid`]: struct.Uuid.html
+/// [`Uuid::parse_str_const`]: struct.Uuid.html#method.parse_str_const
+#[macro_export]
+macro_rules! uuid_namespace {
+    ($(#[$attr:meta])* $vis:vis $name:ident = $uuid:literal) => {
+        $(#[$attr])*
+        $vis const $name: $crate::Uuid = $crate::Uuid::parse_str_const($uuid);
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    uuid_namespace!(HYPHENATED = "6ba7b810-9dad-11d1-80b4-00c04fd430c8");
+    uuid_namespace! {
+        /// A namespace with a doc comment.
+        pub(crate) SIMPLE = "6BA7B8129DAD11D180B400C04FD430C8"
+    }
+
+    #[test]
+    fn test_all() {
+        assert_eq!(
+            ALL,
+            [
+                ("DNS", DNS),
+                ("OID", OID),
+                ("URL", URL),
+                ("X500", X500),
+                ("ETW", ETW),
+            ]
+        );
+        for &(name, ns) in ALL[..4].iter() {
+            assert_eq!(ns.get_version(), Some(Version::Mac), "{}", name);
+        }
+    }
+
+    #[test]
+    fn test_etw() {
+        assert_eq!(
+            ETW.as_bytes(),
+            &[
+                0x48, 0x2c, 0x2d, 0xb2, 0xc3, 0x90, 0x47, 0xc8, 0x87, 0xf8,
+                0x1a, 0x15, 0xbf, 0xc1, 0x30, 0xfb,
+            ]
+        );
+    }
+
+    #[test]
+    fn test_uuid_namespace() {
+        assert_eq!(HYPHENATED, DNS);
+        assert_eq!(SIMPLE, OID);
+    }
+}
@@ -0,0 +1,338 @@
+use super::{error, Error, GROUP_LENS};
+use crate::std::fmt;
+
+/// An [`Error`] rendered against the input that caused it.
+///
+/// The [`Display`] implementation prints the error message, then the input
+/// with carets under the invalid character or group, and a suggested fix
+/// where there's an obvious one.
+///
+/// This is returned by [`Error::render`].
+///
+/// [`Error`]: struct.Error.html
+/// [`Error::render`]: struct.Error.html#method.render
+/// [`Display`]: https://doc.rust-lang.org/std/fmt/trait.Display.html
+#[derive(Clone, Copy, Debug)]
+pub struct Diagnostic<'a> {
+    error: &'a crate::Error,
+    input: &'a str,
+}
+
+impl crate::Error {
+    /// Renders this error against the `input` that caused it, for showing
+    /// to users.
+    ///
+    /// Positions are counted in characters rather than bytes, so the caret
+    /// lines up under non-ASCII input. Errors that don't point into the
+    /// input are rendered with just their message and the input.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use uuid::Uuid;
+    ///
+    /// let input = "67e55044-1Ob1-426f-9247-bb680e5fe0c8";
+    /// let err = Uuid::parse_str(input).unwrap_err();
+    ///
+    /// assert_eq!(
+    ///     err.render(input).to_string(),
+    ///     "\
+    /// error: invalid character: expected an optional prefix of `urn:uuid:` \
+    /// followed by 0123456789abcdefABCDEF-, found O at 10
+    ///   | 67e55044-1Ob1-426f-9247-bb680e5fe0c8
+    ///   |           ^
+    ///   = help: replace `O` with `0`"
+    /// );
+    /// ```
+    pub fn render<'a>(&'a self, input: &'a str) -> Diagnostic<'a> {
+        Diagnostic { error: self, input }
+    }
+}
+
+/// A suggested fix for an error.
+enum Help {
+    /// A letter was used in place of a digit it looks like.
+    Lookalike { found: char, digit: char },
+    /// Whitespace was found in the input.
+    Whitespace,
+    /// Two groups are run together without a hyphen between them.
+    MissingHyphen { after: usize },
+    /// A group has too many digits.
+    Extra { count: usize },
+    /// A group has too few digits.
+    Missing { count: usize },
+}
+
+impl fmt::Display for Help {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            Help::Lookalike { found, digit } => {
+                write!(f, "replace `{}` with `{}`", found, digit)
+            }
+            Help::Whitespace => write!(f, "remove the whitespace"),
+            Help::MissingHyphen { after } => {
+                write!(f, "add a `-` after the first {} digits", after)
+            }
+            Help::Extra { count: 1 } => write!(f, "remove 1 extra character"),
+            Help::Extra { count } => {
+                write!(f, "remove {} extra characters", count)
+            }
+            Help::Missing { count: 1 } => write!(f, "add 1 missing digit"),
+            Help::Missing { count } => {
+                write!(f, "add {} missing digits", count)
+            }
+        }
+    }
+}
+
+impl<'a> Diagnostic<'a> {
+    /// The character range to underline, as a start and a length.
+    fn span(&self, err: Error) -> Option<(usize, usize)> {
+        match err {
+            Error::InvalidCharacter { index, start, .. } => {
+                let index = start + index;
+
+                Some((self.input.get(..index)?.chars().count(), 1))
+            }
+            Error::InvalidGroupLength { group, .. } => {
+                group_span(self.input, group)
+            }
+            Error::UnexpectedEnd { index, .. } => {
+                Some((self.input.get(..index)?.chars().count(), 1))
+            }
+            _ => None,
+        }
+    }
+
+    fn help(&self, err: Error) -> Option<Help> {
+        match err {
+            Error::InvalidCharacter { found, .. } => match found {
+                'O' | 'o' => Some(Help::Lookalike { found, digit: '0' }),
+                'I' | 'l' => Some(Help::Lookalike { found, digit: '1' }),
+                _ if found.is_whitespace() => Some(Help::Whitespace),
+                _ => None,
+            },
+            Error::InvalidGroupLength {
+                expected: error::ExpectedLength::Exact(expected),
+                found,
+                group,
+            } => {
+                if found > expected
+                    && group + 1 < GROUP_LENS.len()
+                    && found == expected + GROUP_LENS[group + 1]
+                {
+                    Some(Help::MissingHyphen { after: expected })
+                } else if found > expected {
+                    Some(Help::Extra {
+                        count: found - expected,
+                    })
+                } else {
+                    Some(Help::Missing {
+                        count: expected - found,
+                    })
+                }
+            }
+            _ => None,
+        }
+    }
+}
+
+/// Finds the characters of the hex digits in the (0-indexed) `group` of a
+/// hyphenated `input`, ignoring any prefix or suffix around the UUID.
+fn group_span(input: &str, group: usize) -> Option<(usize, usize)> {
+    let mut start = 0;
+
+    for (i, segment) in input.split('-').enumerate() {
+        let len = segment.chars().count();
+
+        if i == group {
+            let hex = |c: &char| c.is_ascii_hexdigit();
+
+            return Some(if i == 0 {
+                let digits = segment.chars().rev().take_while(hex).count();
+                (start + len - digits, digits.max(1))
+            } else {
+                (start, segment.chars().take_while(hex).count().max(1))
+            });
+        }
+
+        start += len + 1;
+    }
+
+    None
+}
+
+impl<'a> fmt::Display for Diagnostic<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "error: {}", self.error)?;
+        write!(f, "\n  | {}", self.input)?;
+
+        let err = match self.error.as_parser() {
+            Some(err) => err,
+            None => return Ok(()),
+        };
+
+        if let Some((start, len)) = self.span(err) {
+            write!(f, "\n  | {:start$}", "", start = start)?;
+            for _ in 0..len {
+                f.write_str("^")?;
+            }
+        }
+
+        if let Some(help) = self.help(err) {
+            write!(f, "\n  = help: {}", help)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::std::string::{String, ToString};
+    use crate::{ParseOptions, Uuid};
+
+    fn render(input: &str) -> String {
+        Uuid::parse_str(input)
+            .unwrap_err()
+            .render(input)
+            .to_string()
+    }
+
+    #[test]
+    fn test_render_invalid_character() {
+        assert_eq!(
+            render("67e55044-10b1-426f-9247-bb680e5fe0cX"),
+            "\
+error: invalid character: expected an optional prefix of `urn:uuid:` \
+followed by 0123456789abcdefABCDEF-, found X at 35
+  | 67e55044-10b1-426f-9247-bb680e5fe0cX
+  |                                    ^"
+        );
+        assert_eq!(
+            render("urn:uuid:67e55044-10b1-426f-9247-bb680e5fe0cX"),
+            "\
+error: invalid character: expected an optional prefix of `urn:uuid:` \
+followed by 0123456789abcdefABCDEF-, found X at 35
+  | urn:uuid:67e55044-10b1-426f-9247-bb680e5fe0cX
+  |                                             ^"
+        );
+        assert_eq!(
+            render("{67e55044-10b1-426f-9247-bb680e5fe0cX}"),
+            "\
+error: invalid character: expected 0123456789abcdefABCDEF-, found X at 35
+  | {67e55044-10b1-426f-9247-bb680e5fe0cX}
+  |                                     ^"
+        );
+        assert_eq!(
+            render("urn:uuid:u7e55044-10b1-426f-9247-bb680e5fe0c8"),
+            "\
+error: invalid character: expected an optional prefix of `urn:uuid:` \
+followed by 0123456789abcdefABCDEF-, found u at 0
+  | urn:uuid:u7e55044-10b1-426f-9247-bb680e5fe0c8
+  |          ^"
+        );
+        assert_eq!(
+            render("67e55044-10b1-426f-9247-bb680e5fe0c8 "),
+            "\
+error: invalid length: expected one of [36, 32, 38], found 37
+  | 67e55044-10b1-426f-9247-bb680e5fe0c8 "
+        );
+        assert_eq!(
+            render("67e55044 10b1-426f-9247-bb680e5fe0c8"),
+            "\
+error: invalid character: expected an optional prefix of `urn:uuid:` \
+followed by 0123456789abcdefABCDEF-, found   at 8
+  | 67e55044 10b1-426f-9247-bb680e5fe0c8
+  |         ^
+  = help: remove the whitespace"
+        );
+    }
+
+    #[test]
+    fn test_render_absolute_index() {
+        let input = "urn:uuid:67e55044-10b1-426f-9247-bb680e5fe0cX";
+        let err = Uuid::parse_with(input, &ParseOptions::new()).unwrap_err();
+
+        // `parse_with` counts from the start of the input, including the
+        // prefix, so the caret is in the same place as for `parse_str`.
+        assert_eq!(
+            err.render(input).to_string(),
+            "\
+error: invalid character: expected an optional prefix of `urn:uuid:` \
+followed by 0123456789abcdefABCDEF-, found X at 44
+  | urn:uuid:67e55044-10b1-426f-9247-bb680e5fe0cX
+  |                                             ^"
+        );
+    }
+
+    #[test]
+    fn test_render_non_ascii() {
+        let input = "\u{a0}\u{a0}67e55044-10b1-426f-9247-bb680e5fe0cl";
+        let err =
+            Uuid::parse_with(input, &ParseOptions::lenient()).unwrap_err();
+
+        // The index in the message is in bytes, the caret is in characters.
+        assert_eq!(
+            err.render(input).to_string(),
+            "\
+error: invalid character: expected an optional prefix of `urn:uuid:` \
+followed by 0123456789abcdefABCDEF-, found l at 39
+  | \u{a0}\u{a0}67e55044-10b1-426f-9247-bb680e5fe0cl
+  |                                      ^
+  = help: replace `l` with `1`"
+        );
+    }
+
+    #[test]
+    fn test_render_invalid_group_length() {
+        assert_eq!(
+            render("67e55044-10b1-426f-9247-bb680e5fe0c8a"),
+            "\
+error: invalid length: expected one of [36, 32, 38], found 37
+  | 67e55044-10b1-426f-9247-bb680e5fe0c8a"
+        );
+        assert_eq!(
+            render("F9168C5E-CEB2-4faa-B6BF1-02BF39FA1E4"),
+            "\
+error: invalid group length: expected 4, found 5 in group 3
+  | F9168C5E-CEB2-4faa-B6BF1-02BF39FA1E4
+  |                    ^^^^^
+  = help: remove 1 extra character"
+        );
+        assert_eq!(
+            render("{67e5504-410b1-426f-9247-bb680e5fe0c8}"),
+            "\
+error: invalid group length: expected 8, found 7 in group 0
+  | {67e5504-410b1-426f-9247-bb680e5fe0c8}
+  |  ^^^^^^^
+  = help: add 1 missing digit"
+        );
+        assert_eq!(
+            render("{67e55044-10b1426f-9247-bb680e5fe0c8}"),
+            "\
+error: invalid length: expected one of [36, 32, 38], found 37
+  | {67e55044-10b1426f-9247-bb680e5fe0c8}"
+        );
+        assert_eq!(
+            render("67e55044-10b1426f-9247-bb680e5fe0c8a"),
+            "\
+error: invalid group length: expected 4, found 8 in group 1
+  | 67e55044-10b1426f-9247-bb680e5fe0c8a
+  |          ^^^^^^^^
+  = help: add a `-` after the first 4 digits"
+        );
+    }
+
+    #[test]
+    fn test_render_other_errors() {
+        let err = Uuid::from_slice(&[0; 15]).unwrap_err();
+
+        assert_eq!(
+            err.render("").to_string(),
+            "\
+error: invalid bytes length: expected 16, found 15
+  | "
+        );
+    }
+}
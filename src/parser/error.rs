@@ -16,6 +16,9 @@ pub(crate) enum Error {
         found: char,
         /// The invalid character position.
         index: usize,
+        /// The position in the input that `index` counts from, such as
+        /// after a `urn:uuid:` prefix or opening brace.
+        start: usize,
         /// Indicates the [`Uuid`] starts with `urn:uuid:`.
         ///
         /// This is a special case for [`Urn`] adapter parsing.
@@ -58,6 +61,16 @@ pub(crate) enum Error {
         /// The invalid length found.
         found: usize,
     },
+    /// The input ended before a complete [`Uuid`] was read, such as a
+    /// truncated C `GUID` initializer.
+    ///
+    /// [`Uuid`]: ../struct.Uuid.html
+    UnexpectedEnd {
+        /// What was expected next.
+        expected: &'static str,
+        /// The position of the end of the input.
+        index: usize,
+    },
 }
 
 /// The expected length.
@@ -74,15 +87,52 @@ pub(crate) enum ExpectedLength {
 pub(crate) enum UrnPrefix {
     /// The `urn:uuid:` prefix should optionally provided.
     Optional,
+    /// The `urn:uuid:` prefix doesn't apply to the expected characters,
+    /// such as when parsing a specific format.
+    NotApplicable,
 }
 
 impl Error {
-    fn _description(&self) -> &str {
+    /// Makes the reported index count from the start of the original input,
+    /// for errors found in a substring that starts `by` bytes into it.
+    pub(crate) fn offset(self, by: usize) -> Self {
+        match self {
+            Error::InvalidCharacter {
+                expected,
+                found,
+                index,
+                start,
+                urn,
+            } => Error::InvalidCharacter {
+                expected,
+                found,
+                index: by + start + index,
+                start: 0,
+                urn,
+            },
+            err => err,
+        }
+    }
+
+    pub(super) const fn _description(&self) -> &'static str {
         match *self {
             Error::InvalidCharacter { .. } => "invalid character",
             Error::InvalidGroupCount { .. } => "invalid number of groups",
             Error::InvalidGroupLength { .. } => "invalid group length",
             Error::InvalidLength { .. } => "invalid length",
+            Error::UnexpectedEnd { .. } => "unexpected end of input",
+        }
+    }
+}
+
+impl UrnPrefix {
+    /// The text put before the expected characters in an error message.
+    pub(super) const fn describe(self) -> &'static str {
+        match self {
+            UrnPrefix::Optional => {
+                " an optional prefix of `urn:uuid:` followed by"
+            }
+            UrnPrefix::NotApplicable => "",
         }
     }
 }
@@ -106,19 +156,15 @@ impl fmt::Display for Error {
                 found,
                 index,
                 urn,
-            } => {
-                let urn_str = match urn {
-                    UrnPrefix::Optional => {
-                        " an optional prefix of `urn:uuid:` followed by"
-                    }
-                };
-
-                write!(
-                    f,
-                    "expected{} {}, found {} at {}",
-                    urn_str, expected, found, index
-                )
-            }
+                ..
+            } => write!(
+                f,
+                "expected{} {}, found {} at {}",
+                urn.describe(),
+                expected,
+                found,
+                index
+            ),
             Error::InvalidGroupCount {
                 ref expected,
                 found,
@@ -136,6 +182,9 @@ impl fmt::Display for Error {
                 ref expected,
                 found,
             } => write!(f, "expected {}, found {}", expected, found),
+            Error::UnexpectedEnd { expected, index } => {
+                write!(f, "expected {} at {}", expected, index)
+            }
         }
     }
 }
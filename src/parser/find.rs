@@ -0,0 +1,336 @@
+use super::parse_bytes;
+use crate::std::ops::Range;
+use crate::{adapter, Uuid};
+
+/// An iterator over the UUIDs found in a string.
+///
+/// This is returned by [`find_iter`].
+///
+/// [`find_iter`]: fn.find_iter.html
+#[derive(Clone, Debug)]
+pub struct FindIter<'a> {
+    input: &'a str,
+    position: usize,
+}
+
+/// Finds every [`Uuid`] in free text, such as logs, URLs or stack traces.
+///
+/// Yields the byte range and value of each UUID in the hyphenated, simple,
+/// urn or braced formats, in the order they appear, with the `urn:uuid:`
+/// prefix in any case. A UUID is only found when it isn't part of a longer
+/// word, so a run of 40 hex digits isn't mistaken for a simple UUID.
+/// Matches never overlap, and the longest format wins, so a urn is found as
+/// a whole rather than just its hyphenated part.
+///
+/// Unlike [`Uuid::parse_str`], which only accepts a lowercase `urn:uuid:`
+/// prefix, any case is matched here, since URNs are case-insensitive. So a
+/// found range doesn't always parse with [`Uuid::parse_str`]; use the
+/// yielded [`Uuid`] instead of parsing the range again.
+///
+/// # Examples
+///
+/// ```
+/// use uuid::Uuid;
+///
+/// let log = "GET /users/67e55044-10b1-426f-9247-bb680e5fe0c8/posts 404";
+///
+/// let found: Vec<_> = uuid::find_iter(log).collect();
+///
+/// assert_eq!(
+///     found,
+///     [(
+///         11..47,
+///         Uuid::parse_str("67e55044-10b1-426f-9247-bb680e5fe0c8")?
+///     )]
+/// );
+/// # Ok::<(), uuid::Error>(())
+/// ```
+///
+/// [`Uuid`]: struct.Uuid.html
+/// [`Uuid::parse_str`]: struct.Uuid.html#method.parse_str
+pub fn find_iter(input: &str) -> FindIter<'_> {
+    FindIter { input, position: 0 }
+}
+
+/// Whether `c` can be part of a word, so a UUID can't start or end next
+/// to it.
+fn is_word(c: char) -> bool {
+    c.is_alphanumeric() || c == '_'
+}
+
+impl<'a> FindIter<'a> {
+    /// Parses a UUID in any format that starts at `start`.
+    fn find_at(&self, start: usize) -> Option<(Range<usize>, Uuid)> {
+        let rest = &self.input[start..];
+
+        // The lengths of the formats that can start with the first
+        // character, longest first.
+        let lengths: &[usize] = match rest.as_bytes().first()? {
+            b'u' | b'U' => &[adapter::Urn::LENGTH],
+            b'{' => &[adapter::Braced::LENGTH],
+            b if b.is_ascii_hexdigit() => {
+                &[adapter::Hyphenated::LENGTH, adapter::Simple::LENGTH]
+            }
+            _ => return None,
+        };
+
+        if self.input[..start]
+            .chars()
+            .next_back()
+            .map_or(false, is_word)
+        {
+            return None;
+        }
+
+        for &len in lengths {
+            let candidate = match rest.get(..len) {
+                Some(candidate) => candidate,
+                None => continue,
+            };
+
+            if rest[len..].chars().next().map_or(false, is_word) {
+                continue;
+            }
+
+            if let Some(uuid) = parse_candidate(candidate.as_bytes()) {
+                return Some((start..start + len, uuid));
+            }
+        }
+
+        None
+    }
+}
+
+/// Parses a UUID in any format, with the `urn:uuid:` prefix in any case.
+fn parse_candidate(candidate: &[u8]) -> Option<Uuid> {
+    const PREFIX: &[u8] = b"urn:uuid:";
+
+    if candidate.len() == adapter::Urn::LENGTH {
+        if !candidate[..PREFIX.len()].eq_ignore_ascii_case(PREFIX) {
+            return None;
+        }
+
+        let mut urn = [0u8; adapter::Urn::LENGTH];
+        urn[..PREFIX.len()].copy_from_slice(PREFIX);
+        urn[PREFIX.len()..].copy_from_slice(&candidate[PREFIX.len()..]);
+
+        return parse_bytes(&urn, |_| '\0').ok();
+    }
+
+    parse_bytes(candidate, |_| '\0').ok()
+}
+
+impl<'a> Iterator for FindIter<'a> {
+    type Item = (Range<usize>, Uuid);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while let Some(c) = self.input[self.position..].chars().next() {
+            if let Some((range, uuid)) = self.find_at(self.position) {
+                self.position = range.end;
+                return Some((range, uuid));
+            }
+
+            // A UUID can't start inside a word, so skip the rest of it
+            // rather than trying every position in a long run of digits.
+            self.position += if is_word(c) {
+                self.input[self.position..]
+                    .chars()
+                    .take_while(|&c| is_word(c))
+                    .map(char::len_utf8)
+                    .sum()
+            } else {
+                c.len_utf8()
+            };
+        }
+
+        None
+    }
+}
+
+#[cfg(feature = "std")]
+mod std_support {
+    use super::*;
+    use crate::std::{borrow::Cow, string::String};
+
+    /// Replaces every [`Uuid`] found in `input` by [`find_iter`] with the
+    /// string returned by `replacement`.
+    ///
+    /// The input is only copied if a UUID is found.
+    ///
+    /// # Examples
+    ///
+    /// Redacting UUIDs:
+    ///
+    /// ```
+    /// let log = "user 67e55044-10b1-426f-9247-bb680e5fe0c8 logged in";
+    ///
+    /// assert_eq!(
+    ///     uuid::replace_all(log, |_| "<redacted>"),
+    ///     "user <redacted> logged in"
+    /// );
+    /// ```
+    ///
+    /// Normalizing UUIDs to one format:
+    ///
+    /// ```
+    /// let text = "{67E55044-10B1-426F-9247-BB680E5FE0C8}";
+    ///
+    /// assert_eq!(
+    ///     uuid::replace_all(text, |uuid| uuid.to_urn().to_string()),
+    ///     "urn:uuid:67e55044-10b1-426f-9247-bb680e5fe0c8"
+    /// );
+    /// ```
+    ///
+    /// [`Uuid`]: struct.Uuid.html
+    /// [`find_iter`]: fn.find_iter.html
+    pub fn replace_all<F, R>(input: &str, mut replacement: F) -> Cow<'_, str>
+    where
+        F: FnMut(&Uuid) -> R,
+        R: AsRef<str>,
+    {
+        let mut found = find_iter(input).peekable();
+        if found.peek().is_none() {
+            return Cow::Borrowed(input);
+        }
+
+        let mut output = String::with_capacity(input.len());
+        let mut last = 0;
+        for (range, uuid) in found {
+            output.push_str(&input[last..range.start]);
+            output.push_str(replacement(&uuid).as_ref());
+            last = range.end;
+        }
+        output.push_str(&input[last..]);
+
+        Cow::Owned(output)
+    }
+}
+
+#[cfg(feature = "std")]
+pub use self::std_support::replace_all;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::std::vec::Vec;
+    #[cfg(feature = "std")]
+    use crate::std::{borrow::Cow, string::ToString};
+
+    const UUID: &str = "67e55044-10b1-426f-9247-bb680e5fe0c8";
+
+    fn expected() -> Uuid {
+        Uuid::parse_str(UUID).unwrap()
+    }
+
+    fn find(input: &str) -> Vec<(Range<usize>, Uuid)> {
+        find_iter(input).collect()
+    }
+
+    #[test]
+    fn test_find_formats() {
+        for input in &[
+            "67e55044-10b1-426f-9247-bb680e5fe0c8",
+            "67e5504410b1426f9247bb680e5fe0c8",
+            "urn:uuid:67e55044-10b1-426f-9247-bb680e5fe0c8",
+            "{67e55044-10b1-426f-9247-bb680e5fe0c8}",
+            "67E55044-10B1-426F-9247-BB680E5FE0C8",
+            "URN:UUID:67e55044-10b1-426f-9247-bb680e5fe0c8",
+            "Urn:Uuid:67E55044-10B1-426F-9247-BB680E5FE0C8",
+        ] {
+            let text = format!("id=({}).", input);
+
+            assert_eq!(
+                find(&text),
+                [(4..4 + input.len(), expected())],
+                "{}",
+                text
+            );
+        }
+    }
+
+    #[test]
+    fn test_find_many() {
+        let text = format!(
+            "{} and {}\n\u{a0}{}, {}",
+            UUID,
+            Uuid::nil().to_simple(),
+            UUID,
+            Uuid::nil().to_urn()
+        );
+
+        assert_eq!(
+            find(&text),
+            [
+                (0..36, expected()),
+                (41..73, Uuid::nil()),
+                (76..112, expected()),
+                (114..159, Uuid::nil()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_find_word_boundaries() {
+        for text in &[
+            "x67e55044-10b1-426f-9247-bb680e5fe0c8",
+            "67e55044-10b1-426f-9247-bb680e5fe0c8x",
+            "_67e55044-10b1-426f-9247-bb680e5fe0c8",
+            "\u{e9}67e55044-10b1-426f-9247-bb680e5fe0c8",
+            "67e5504410b1426f9247bb680e5fe0c8a",
+            "da39a3ee5e6b4b0d3255bfef95601890afd80709",
+            "67e55044-10b1-426f-9247-bb680e5fe0c",
+            "urn:uuid:67e55044-10b1-426f-9247-bb680e5fe0c8x",
+            "",
+        ] {
+            assert_eq!(find(text), [], "{}", text);
+        }
+
+        assert_eq!(
+            find("/67e55044-10b1-426f-9247-bb680e5fe0c8?"),
+            [(1..37, expected())]
+        );
+        assert_eq!(
+            find("x{67e55044-10b1-426f-9247-bb680e5fe0c8}"),
+            [(2..38, expected())]
+        );
+        assert_eq!(find("URN:UUID:67e55044-10b1-426f-9247-bb680e5fe0c8x"), []);
+        assert_eq!(
+            find(&format!("urn:uuid:{}_ {}", UUID, UUID)),
+            [(47..83, expected())]
+        );
+    }
+
+    #[test]
+    fn test_find_long_words() {
+        let mut text = [b'a'; 4096];
+        text[1000] = b' ';
+        text[1001..1037].copy_from_slice(UUID.as_bytes());
+        text[1037] = b' ';
+
+        let text = crate::std::str::from_utf8(&text).unwrap();
+
+        assert_eq!(find(text), [(1001..1037, expected())]);
+        assert_eq!(find(&text[..1000]), []);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_replace_all() {
+        let text = format!("a {} b {} c", UUID, Uuid::nil().to_braced());
+
+        assert_eq!(replace_all(&text, |_| "***"), "a *** b *** c");
+        assert_eq!(
+            replace_all(&text, |uuid| uuid.to_simple().to_string()),
+            format!(
+                "a {} b {} c",
+                expected().to_simple(),
+                Uuid::nil().to_simple()
+            )
+        );
+
+        match replace_all("nothing to see", |_| "***") {
+            Cow::Borrowed(text) => assert_eq!(text, "nothing to see"),
+            Cow::Owned(_) => panic!("expected the input to be borrowed"),
+        }
+    }
+}
@@ -0,0 +1,156 @@
+use super::parse;
+use crate::std::vec::Vec;
+use crate::{adapter, Uuid};
+
+impl Uuid {
+    /// Parses every `Uuid` in a string of UUIDs with `separator` between
+    /// them.
+    ///
+    /// Each UUID may be in any of the formats supported by [`parse_str`].
+    /// A single trailing separator, such as the final newline of a file, is
+    /// allowed. An empty `input` contains no UUIDs.
+    ///
+    /// If a UUID fails to parse, the error is returned along with the index
+    /// of that UUID in `input`, counting from zero.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `separator` is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use uuid::Uuid;
+    ///
+    /// let uuids = Uuid::parse_many(
+    ///     "67e55044-10b1-426f-9247-bb680e5fe0c8\n\
+    ///      00000000000000000000000000000000\n",
+    ///     "\n",
+    /// )
+    /// .unwrap();
+    ///
+    /// assert_eq!(
+    ///     uuids,
+    ///     [
+    ///         Uuid::parse_str("67e55044-10b1-426f-9247-bb680e5fe0c8")?,
+    ///         Uuid::nil()
+    ///     ]
+    /// );
+    ///
+    /// let (index, _) =
+    ///     Uuid::parse_many("00000000000000000000000000000000,x", ",")
+    ///         .unwrap_err();
+    ///
+    /// assert_eq!(index, 1);
+    /// # Ok::<(), uuid::Error>(())
+    /// ```
+    ///
+    /// [`parse_str`]: #method.parse_str
+    pub fn parse_many(
+        input: &str,
+        separator: &str,
+    ) -> Result<Vec<Uuid>, (usize, crate::Error)> {
+        assert!(!separator.is_empty(), "the separator must not be empty");
+
+        if input.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let input = input.strip_suffix(separator).unwrap_or(input);
+
+        // Enough room for simple UUIDs, the shortest format.
+        let mut uuids = Vec::with_capacity(
+            input.len() / (adapter::Simple::LENGTH + separator.len()) + 1,
+        );
+
+        for (index, item) in input.split(separator).enumerate() {
+            match parse(item) {
+                Ok(uuid) => uuids.push(uuid),
+                Err(err) => return Err((index, err.into())),
+            }
+        }
+
+        Ok(uuids)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parser::{error, Error};
+    use crate::std::string::ToString;
+    use crate::test_util;
+
+    #[test]
+    fn test_parse_many() {
+        let uuids = [test_util::new(), test_util::new2(), Uuid::nil()];
+
+        let mixed = format!(
+            "{},{},{}",
+            uuids[0].to_simple(),
+            uuids[1].to_urn(),
+            uuids[2].to_braced()
+        );
+        assert_eq!(Uuid::parse_many(&mixed, ","), Ok(uuids.to_vec()));
+
+        let mut lines = Vec::new();
+        adapter::encode_many(
+            &uuids,
+            adapter::Format::Hyphenated,
+            &mut lines,
+            "\r\n",
+        );
+        let lines = crate::std::string::String::from_utf8(lines).unwrap();
+        assert_eq!(Uuid::parse_many(&lines, "\r\n"), Ok(uuids.to_vec()));
+        assert_eq!(
+            Uuid::parse_many(&(lines + "\r\n"), "\r\n"),
+            Ok(uuids.to_vec())
+        );
+
+        assert_eq!(Uuid::parse_many("", "\n"), Ok(Vec::new()));
+        assert_eq!(
+            Uuid::parse_many(&uuids[0].to_string(), "\n"),
+            Ok(vec![uuids[0]])
+        );
+    }
+
+    #[test]
+    fn test_parse_many_invalid() {
+        let input = "67e55044-10b1-426f-9247-bb680e5fe0c8\n\
+                     67e55044-10b1-426f-9247-bb680e5fe0cX\n";
+
+        assert_eq!(
+            Uuid::parse_many(input, "\n")
+                .map_err(|(index, err)| (index, err.expect_parser())),
+            Err((
+                1,
+                Error::InvalidCharacter {
+                    expected: "0123456789abcdefABCDEF-",
+                    found: 'X',
+                    index: 35,
+                    start: 0,
+                    urn: error::UrnPrefix::Optional,
+                }
+            ))
+        );
+
+        // Only one trailing separator is allowed.
+        assert_eq!(
+            Uuid::parse_many("67e5504410b1426f9247bb680e5fe0c8\n\n", "\n")
+                .map_err(|(index, err)| (index, err.expect_parser())),
+            Err((
+                1,
+                Error::InvalidLength {
+                    expected: error::ExpectedLength::Any(&[36, 32, 38]),
+                    found: 0,
+                }
+            ))
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_parse_many_empty_separator() {
+        let _ = Uuid::parse_many("67e5504410b1426f9247bb680e5fe0c8", "");
+    }
+}
@@ -0,0 +1,161 @@
+//! Error messages written in a `const` context, for
+//! [`Uuid::parse_str_const`].
+//!
+//! `format!` isn't available there, so this writes the same message as the
+//! `Display` implementation of [`Error`] into a fixed-size buffer.
+//!
+//! [`Uuid::parse_str_const`]: ../../struct.Uuid.html#method.parse_str_const
+//! [`Error`]: ../error/enum.Error.html
+
+use super::{error::ExpectedLength, Error, Invalid};
+use crate::std::str;
+
+/// A message written into a fixed-size buffer.
+pub(super) struct Message {
+    bytes: [u8; 256],
+    len: usize,
+}
+
+impl Message {
+    /// Writes the message of the error in `invalid`, which was found in
+    /// `input`.
+    pub(super) const fn new(invalid: &Invalid, input: &str) -> Self {
+        let message = Message {
+            bytes: [0; 256],
+            len: 0,
+        }
+        .push_str(invalid.error._description())
+        .push_str(": ");
+
+        match invalid.error {
+            Error::InvalidCharacter {
+                expected,
+                index,
+                urn,
+                ..
+            } => message
+                .push_str("expected")
+                .push_str(urn.describe())
+                .push_str(" ")
+                .push_str(expected)
+                .push_str(", found ")
+                .push_char_at(input.as_bytes(), invalid.at)
+                .push_str(" at ")
+                .push_usize(index),
+            Error::InvalidGroupCount { expected, found } => message
+                .push_str("expected ")
+                .push_expected(expected)
+                .push_str(", found ")
+                .push_usize(found),
+            Error::InvalidGroupLength {
+                expected,
+                found,
+                group,
+            } => message
+                .push_str("expected ")
+                .push_expected(expected)
+                .push_str(", found ")
+                .push_usize(found)
+                .push_str(" in group ")
+                .push_usize(group),
+            Error::InvalidLength { expected, found } => message
+                .push_str("expected ")
+                .push_expected(expected)
+                .push_str(", found ")
+                .push_usize(found),
+            Error::UnexpectedEnd { expected, index } => message
+                .push_str("expected ")
+                .push_str(expected)
+                .push_str(" at ")
+                .push_usize(index),
+        }
+    }
+
+    pub(super) const fn as_str(&self) -> &str {
+        // Slicing isn't available in a `const fn`, but slice patterns are.
+        let mut bytes: &[u8] = &self.bytes;
+        while bytes.len() > self.len {
+            if let [rest @ .., _] = bytes {
+                bytes = rest;
+            }
+        }
+
+        // SAFETY: only whole strings and characters are ever written.
+        unsafe { str::from_utf8_unchecked(bytes) }
+    }
+
+    /// Writes `bytes[start..end]`, or nothing if it doesn't fit, so a
+    /// character is never cut in half.
+    const fn push_bytes(
+        mut self,
+        bytes: &[u8],
+        start: usize,
+        end: usize,
+    ) -> Self {
+        if self.len + (end - start) > self.bytes.len() {
+            return self;
+        }
+
+        let mut i = start;
+        while i < end {
+            self.bytes[self.len] = bytes[i];
+            self.len += 1;
+            i += 1;
+        }
+
+        self
+    }
+
+    const fn push_str(self, s: &str) -> Self {
+        self.push_bytes(s.as_bytes(), 0, s.len())
+    }
+
+    /// Writes the character whose first byte is at `at` in `input`.
+    const fn push_char_at(self, input: &[u8], at: usize) -> Self {
+        let len = match input[at] {
+            0x00..=0x7f => 1,
+            0xc0..=0xdf => 2,
+            0xe0..=0xef => 3,
+            _ => 4,
+        };
+
+        self.push_bytes(input, at, at + len)
+    }
+
+    const fn push_usize(self, mut n: usize) -> Self {
+        let mut digits = [0u8; 20];
+        let mut start = digits.len();
+        loop {
+            start -= 1;
+            digits[start] = b'0' + (n % 10) as u8;
+            n /= 10;
+
+            if n == 0 {
+                break;
+            }
+        }
+
+        self.push_bytes(&digits, start, digits.len())
+    }
+
+    /// Writes `expected` like its `Display` implementation.
+    const fn push_expected(self, expected: ExpectedLength) -> Self {
+        match expected {
+            ExpectedLength::Any(crits) => {
+                let mut message = self.push_str("one of [");
+
+                let mut i = 0;
+                while i < crits.len() {
+                    if i > 0 {
+                        message = message.push_str(", ");
+                    }
+                    message = message.push_usize(crits[i]);
+                    i += 1;
+                }
+
+                message.push_str("]")
+            }
+            ExpectedLength::Exact(crit) => self.push_usize(crit),
+        }
+    }
+}
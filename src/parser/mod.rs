@@ -16,18 +16,23 @@
 pub(crate) mod error;
 pub(crate) use self::error::Error;
 
-use crate::{adapter, Uuid};
-
-/// Check if the length matches any of the given criteria lengths.
-fn len_matches_any(len: usize, crits: &[usize]) -> bool {
-    for crit in crits {
-        if len == *crit {
-            return true;
-        }
-    }
+mod diagnostic;
+mod find;
+#[cfg(feature = "std")]
+mod many;
+mod message;
+mod options;
+mod simd;
+mod strict;
+mod utf16;
+
+pub use self::diagnostic::Diagnostic;
+#[cfg(feature = "std")]
+pub use self::find::replace_all;
+pub use self::find::{find_iter, FindIter};
+pub use self::options::ParseOptions;
 
-    false
-}
+use crate::{adapter, Uuid};
 
 /// Check if the length matches any criteria lengths in the given range
 /// (inclusive).
@@ -48,154 +53,373 @@ const ACC_GROUP_LENS: [usize; 5] = [8, 12, 16, 20, 32];
 // Length of each hyphenated group in hex digits.
 const GROUP_LENS: [usize; 5] = [8, 4, 4, 4, 12];
 
-impl Uuid {
-    /// Parses a `Uuid` from a string of hexadecimal digits with optional
-    /// hyphens.
-    ///
-    /// Any of the formats generated by this module (simple, hyphenated, urn)
-    /// are supported by this parsing function.
-    pub fn parse_str(mut input: &str) -> Result<Uuid, crate::Error> {
-        // Ensure length is valid for any of the supported formats
-        let len = input.len();
-
-        if len == adapter::Urn::LENGTH && input.starts_with("urn:uuid:") {
-            input = &input[9..];
-        } else if !len_matches_any(
-            len,
-            &[adapter::Hyphenated::LENGTH, adapter::Simple::LENGTH],
-        ) {
-            Err(Error::InvalidLength {
-                expected: error::ExpectedLength::Any(&[
-                    adapter::Hyphenated::LENGTH,
-                    adapter::Simple::LENGTH,
-                ]),
-                found: len,
-            })?;
+/// The lengths reported for input that isn't as long as any format.
+pub(crate) const EXPECTED_LENGTHS: error::ExpectedLength =
+    error::ExpectedLength::Any(&[
+        adapter::Hyphenated::LENGTH,
+        adapter::Simple::LENGTH,
+        adapter::Braced::LENGTH,
+    ]);
+
+/// The length of the `urn:uuid:` prefix or opening brace that is skipped
+/// before the UUID in `input`, if it has one.
+fn prefix_len(input: &[u8]) -> usize {
+    let len = input.len();
+
+    if len == adapter::Urn::LENGTH && input.starts_with(b"urn:uuid:") {
+        9
+    } else if len == adapter::Braced::LENGTH
+        && input.starts_with(b"{")
+        && input.ends_with(b"}")
+    {
+        1
+    } else {
+        0
+    }
+}
+
+/// Parses a `Uuid` from any of the formats generated by the adapters.
+fn parse(input: &str) -> Result<Uuid, Error> {
+    parse_bytes(input.as_bytes(), |index| {
+        input[index..].chars().next().unwrap()
+    })
+}
+
+/// Parses a `Uuid` from the bytes of any of the formats generated by the
+/// adapters.
+///
+/// The invalid character in an error is looked up by `found`, given the
+/// index of its first byte in `input`.
+fn parse_bytes(
+    input: &[u8],
+    found: impl Fn(usize) -> char,
+) -> Result<Uuid, Error> {
+    // Valid input is usually handled by the vectorized parser, leaving
+    // anything else for the scalar parser to describe.
+    let prefix = prefix_len(input);
+    let suffix = if prefix == 1 { 1 } else { 0 };
+    if let Some(uuid) = simd::parse(&input[prefix..input.len() - suffix]) {
+        return Ok(uuid);
+    }
+
+    parse_scalar(input)
+        .map(Uuid::from_bytes)
+        .map_err(|invalid| invalid.into_error(found))
+}
+
+/// An error found by [`parse_scalar`], along with the byte index of the
+/// invalid character in the input, if there is one.
+///
+/// The `found` character of an [`Error::InvalidCharacter`] is left for the
+/// caller to look up, as only it knows how the input is encoded.
+///
+/// [`parse_scalar`]: fn.parse_scalar.html
+/// [`Error::InvalidCharacter`]: error/enum.Error.html#variant.InvalidCharacter
+struct Invalid {
+    error: Error,
+    at: usize,
+}
+
+impl Invalid {
+    const fn new(error: Error) -> Self {
+        Invalid { error, at: 0 }
+    }
+
+    const fn character(
+        expected: &'static str,
+        start: usize,
+        index: usize,
+        urn: error::UrnPrefix,
+    ) -> Self {
+        Invalid {
+            error: Error::InvalidCharacter {
+                expected,
+                found: '\0',
+                index,
+                start,
+                urn,
+            },
+            at: start + index,
         }
+    }
 
-        // `digit` counts only hexadecimal digits, `i_char` counts all chars.
-        let mut digit = 0;
-        let mut group = 0;
-        let mut acc = 0;
-        let mut buffer = [0u8; 16];
-
-        for (i_char, chr) in input.bytes().enumerate() {
-            if digit as usize >= adapter::Simple::LENGTH && group != 4 {
-                if group == 0 {
-                    Err(Error::InvalidLength {
-                        expected: error::ExpectedLength::Any(&[
-                            adapter::Hyphenated::LENGTH,
-                            adapter::Simple::LENGTH,
-                        ]),
-                        found: len,
-                    })?;
-                }
+    fn into_error(self, found: impl Fn(usize) -> char) -> Error {
+        match self.error {
+            Error::InvalidCharacter {
+                expected,
+                index,
+                start,
+                urn,
+                ..
+            } => Error::InvalidCharacter {
+                expected,
+                found: found(self.at),
+                index,
+                start,
+                urn,
+            },
+            err => err,
+        }
+    }
+}
 
-                Err(Error::InvalidGroupCount {
-                    expected: error::ExpectedLength::Any(&[1, 5]),
-                    found: group + 1,
-                })?;
+/// Parses the bytes of any of the formats generated by the adapters, one
+/// byte at a time.
+///
+/// This is the parser behind both [`parse_bytes`] and
+/// [`Uuid::parse_str_const`], so it's a `const fn`.
+///
+/// [`parse_bytes`]: fn.parse_bytes.html
+/// [`Uuid::parse_str_const`]: ../struct.Uuid.html#method.parse_str_const
+const fn parse_scalar(input: &[u8]) -> Result<[u8; 16], Invalid> {
+    // Ensure length is valid for any of the supported formats
+    let len = input.len();
+
+    // The range of `input` that holds the UUID itself.
+    let (start, end, braced) =
+        if len == adapter::Urn::LENGTH && is_urn_prefix(input) {
+            (9, len, false)
+        } else if len == adapter::Braced::LENGTH {
+            if input[0] != b'{' {
+                return Err(Invalid::character(
+                    "{",
+                    0,
+                    0,
+                    error::UrnPrefix::NotApplicable,
+                ));
             }
 
-            if digit % 2 == 0 {
-                // First digit of the byte.
-                match chr {
-                    // Calulate upper half.
-                    b'0'..=b'9' => acc = chr - b'0',
-                    b'a'..=b'f' => acc = chr - b'a' + 10,
-                    b'A'..=b'F' => acc = chr - b'A' + 10,
-                    // Found a group delimiter
-                    b'-' => {
-                        // TODO: remove the u8 cast
-                        // BODY: this only needed until we switch to
-                        //       ParseError
-                        if ACC_GROUP_LENS[group] as u8 != digit {
-                            // Calculate how many digits this group consists of
-                            // in the input.
-                            let found = if group > 0 {
-                                // TODO: remove the u8 cast
-                                // BODY: this only needed until we switch to
-                                //       ParseError
-                                digit - ACC_GROUP_LENS[group - 1] as u8
-                            } else {
-                                digit
-                            };
-
-                            Err(Error::InvalidGroupLength {
-                                expected: error::ExpectedLength::Exact(
-                                    GROUP_LENS[group],
-                                ),
-                                found: found as usize,
-                                group,
-                            })?;
-                        }
-                        // Next group, decrement digit, it is incremented again
-                        // at the bottom.
-                        group += 1;
-                        digit -= 1;
-                    }
-                    _ => {
-                        Err(Error::InvalidCharacter {
-                            expected: "0123456789abcdefABCDEF-",
-                            found: input[i_char..].chars().next().unwrap(),
-                            index: i_char,
-                            urn: error::UrnPrefix::Optional,
-                        })?;
-                    }
-                }
-            } else {
-                // Second digit of the byte, shift the upper half.
-                acc *= 16;
-                match chr {
-                    b'0'..=b'9' => acc += chr - b'0',
-                    b'a'..=b'f' => acc += chr - b'a' + 10,
-                    b'A'..=b'F' => acc += chr - b'A' + 10,
-                    b'-' => {
-                        // The byte isn't complete yet.
-                        let found = if group > 0 {
-                            // TODO: remove the u8 cast
-                            // BODY: this only needed until we switch to
-                            //       ParseError
-                            digit - ACC_GROUP_LENS[group - 1] as u8
-                        } else {
-                            digit
-                        };
-
-                        Err(Error::InvalidGroupLength {
-                            expected: error::ExpectedLength::Exact(
-                                GROUP_LENS[group],
-                            ),
-                            found: found as usize,
-                            group,
-                        })?;
-                    }
-                    _ => {
-                        Err(Error::InvalidCharacter {
-                            expected: "0123456789abcdefABCDEF-",
-                            found: input[i_char..].chars().next().unwrap(),
-                            index: i_char,
-                            urn: error::UrnPrefix::Optional,
-                        })?;
-                    }
+            if input[len - 1] != b'}' {
+                // The last character may take more than one byte.
+                let mut index = len - 1;
+                while input[index] & 0xc0 == 0x80 {
+                    index -= 1;
                 }
-                buffer[(digit / 2) as usize] = acc;
+
+                return Err(Invalid::character(
+                    "}",
+                    0,
+                    index,
+                    error::UrnPrefix::NotApplicable,
+                ));
+            }
+
+            (1, len - 1, true)
+        } else if len == adapter::Hyphenated::LENGTH
+            || len == adapter::Simple::LENGTH
+        {
+            (0, len, false)
+        } else {
+            return Err(Invalid::new(Error::InvalidLength {
+                expected: EXPECTED_LENGTHS,
+                found: len,
+            }));
+        };
+
+    // Only the hyphenated format can be braced, and a `urn:uuid:` prefix
+    // can't appear inside the braces.
+    let (expected_groups, urn) = if braced {
+        (
+            error::ExpectedLength::Exact(5),
+            error::UrnPrefix::NotApplicable,
+        )
+    } else {
+        (
+            error::ExpectedLength::Any(&[1, 5]),
+            error::UrnPrefix::Optional,
+        )
+    };
+
+    // `digit` counts only hexadecimal digits, `group` the hyphens seen.
+    let mut digit = 0;
+    let mut group = 0;
+    let mut buffer = [0u8; 16];
+
+    let mut i = start;
+    while i < end {
+        if digit >= adapter::Simple::LENGTH && group != 4 {
+            if group == 0 && !braced {
+                return Err(Invalid::new(Error::InvalidLength {
+                    expected: EXPECTED_LENGTHS,
+                    found: len,
+                }));
             }
-            digit += 1;
+
+            return Err(Invalid::new(Error::InvalidGroupCount {
+                expected: expected_groups,
+                found: group + 1,
+            }));
         }
 
-        // Now check the last group.
-        // TODO: remove the u8 cast
-        // BODY: this only needed until we switch to
-        //       ParseError
-        if ACC_GROUP_LENS[4] as u8 != digit {
-            Err(Error::InvalidGroupLength {
-                expected: error::ExpectedLength::Exact(GROUP_LENS[4]),
-                found: (digit as usize - ACC_GROUP_LENS[3]),
-                group,
-            })?;
+        let chr = input[i];
+        let value = match chr {
+            b'0'..=b'9' => chr - b'0',
+            b'a'..=b'f' => chr - b'a' + 10,
+            b'A'..=b'F' => chr - b'A' + 10,
+            b'-' => {
+                // A hyphen is only allowed between complete groups.
+                if ACC_GROUP_LENS[group] != digit {
+                    // Calculate how many digits this group consists of in
+                    // the input.
+                    let found = if group > 0 {
+                        digit - ACC_GROUP_LENS[group - 1]
+                    } else {
+                        digit
+                    };
+
+                    return Err(Invalid::new(Error::InvalidGroupLength {
+                        expected: error::ExpectedLength::Exact(
+                            GROUP_LENS[group],
+                        ),
+                        found,
+                        group,
+                    }));
+                }
+
+                group += 1;
+                i += 1;
+                continue;
+            }
+            _ => {
+                return Err(Invalid::character(
+                    "0123456789abcdefABCDEF-",
+                    start,
+                    i - start,
+                    urn,
+                ));
+            }
+        };
+
+        buffer[digit / 2] = buffer[digit / 2] << 4 | value;
+        digit += 1;
+        i += 1;
+    }
+
+    // Now check the last group.
+    if digit != ACC_GROUP_LENS[4] {
+        return Err(Invalid::new(Error::InvalidGroupLength {
+            expected: error::ExpectedLength::Exact(GROUP_LENS[4]),
+            found: digit - ACC_GROUP_LENS[3],
+            group,
+        }));
+    }
+
+    Ok(buffer)
+}
+
+/// Whether `input` starts with the `urn:uuid:` prefix.
+const fn is_urn_prefix(input: &[u8]) -> bool {
+    let prefix = b"urn:uuid:";
+    if input.len() < prefix.len() {
+        return false;
+    }
+
+    let mut i = 0;
+    while i < prefix.len() {
+        if input[i] != prefix[i] {
+            return false;
         }
+        i += 1;
+    }
+
+    true
+}
+
+impl Uuid {
+    /// Parses a `Uuid` from a string of hexadecimal digits with optional
+    /// hyphens.
+    ///
+    /// Any of the formats generated by this module (simple, hyphenated, urn,
+    /// braced) are supported by this parsing function.
+    pub fn parse_str(input: &str) -> Result<Uuid, crate::Error> {
+        Ok(parse(input)?)
+    }
+
+    /// Parses a `Uuid` from a slice of ASCII bytes.
+    ///
+    /// This accepts the same formats and returns the same errors as
+    /// [`parse_str`], without first checking that `input` is valid UTF-8.
+    /// Indexes in errors are byte offsets, just like those reported by
+    /// [`parse_str`], and a byte that isn't ASCII is reported as
+    /// [`char::REPLACEMENT_CHARACTER`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use uuid::Uuid;
+    ///
+    /// let uuid =
+    ///     Uuid::try_parse_ascii(b"67e55044-10b1-426f-9247-bb680e5fe0c8")?;
+    ///
+    /// assert_eq!(
+    ///     uuid,
+    ///     Uuid::parse_str("67e55044-10b1-426f-9247-bb680e5fe0c8")?
+    /// );
+    /// assert!(
+    ///     Uuid::try_parse_ascii(b"67e55044-10b1-426f-9247-bb680e5fe0\xff8")
+    ///         .is_err()
+    /// );
+    /// # Ok::<(), uuid::Error>(())
+    /// ```
+    ///
+    /// [`parse_str`]: #method.parse_str
+    /// [`char::REPLACEMENT_CHARACTER`]: https://doc.rust-lang.org/std/primitive.char.html#associatedconstant.REPLACEMENT_CHARACTER
+    pub fn try_parse_ascii(input: &[u8]) -> Result<Uuid, crate::Error> {
+        Ok(parse_bytes(input, |index| {
+            let chr = input[index];
+
+            if chr.is_ascii() {
+                char::from(chr)
+            } else {
+                crate::std::char::REPLACEMENT_CHARACTER
+            }
+        })?)
+    }
 
-        Ok(Uuid::from_bytes(buffer))
+    /// Parses a `Uuid` from a string of hexadecimal digits with optional
+    /// hyphens, in a `const` context.
+    ///
+    /// This accepts the same formats as [`parse_str`], but can be used to
+    /// initialize a `const` or `static`. Invalid input causes a panic, which
+    /// in a `const` context is reported as a compile error. Use [`parse_str`]
+    /// to handle the error at runtime instead.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `input` isn't a valid simple, hyphenated, urn or braced UUID,
+    /// with the message of the error [`parse_str`] would return.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use uuid::Uuid;
+    ///
+    /// const ID: Uuid =
+    ///     Uuid::parse_str_const("67e55044-10b1-426f-9247-bb680e5fe0c8");
+    ///
+    /// assert_eq!(
+    ///     ID,
+    ///     Uuid::parse_str("67e55044-10b1-426f-9247-bb680e5fe0c8").unwrap()
+    /// );
+    /// ```
+    ///
+    /// Invalid input fails to compile:
+    ///
+    /// ```compile_fail
+    /// use uuid::Uuid;
+    ///
+    /// const ID: Uuid =
+    ///     Uuid::parse_str_const("67e55044-10b1-426f-9247-bb680e5fe0cX");
+    /// ```
+    ///
+    /// [`parse_str`]: #method.parse_str
+    pub const fn parse_str_const(input: &str) -> Uuid {
+        match parse_scalar(input.as_bytes()) {
+            Ok(bytes) => Uuid::from_bytes(bytes),
+            Err(invalid) => {
+                panic!("{}", message::Message::new(&invalid, input).as_str())
+            }
+        }
     }
 }
 
@@ -210,6 +434,7 @@ mod tests {
             error::ExpectedLength::Any(&[
                 adapter::Hyphenated::LENGTH,
                 adapter::Simple::LENGTH,
+                adapter::Braced::LENGTH,
             ]);
 
         const EXPECTED_GROUP_COUNTS: error::ExpectedLength =
@@ -259,6 +484,7 @@ mod tests {
                 expected: EXPECTED_CHARS,
                 found: 'G',
                 index: 20,
+                start: 0,
                 urn: error::UrnPrefix::Optional,
             })
         );
@@ -306,6 +532,7 @@ mod tests {
                 expected: EXPECTED_CHARS,
                 found: 'X',
                 index: 18,
+                start: 0,
                 urn: error::UrnPrefix::Optional,
             })
         );
@@ -365,6 +592,7 @@ mod tests {
                 expected: EXPECTED_CHARS,
                 found: '%',
                 index: 15,
+                start: 0,
                 urn: error::UrnPrefix::Optional,
             })
         );
@@ -388,6 +616,9 @@ mod tests {
             "urn:uuid:67e55044-10b1-426f-9247-bb680e5fe0c8"
         )
         .is_ok());
+        assert!(
+            Uuid::parse_str("{67e55044-10b1-426f-9247-bb680e5fe0c8}").is_ok()
+        );
 
         // Nil
         let nil = Uuid::nil();
@@ -406,6 +637,10 @@ mod tests {
         let uuid_out = Uuid::parse_str(&orig_str).unwrap();
         assert_eq!(uuid_orig, uuid_out);
 
+        let braced_str = uuid_orig.to_braced().to_string();
+        let uuid_out = Uuid::parse_str(&braced_str).unwrap();
+        assert_eq!(uuid_orig, uuid_out);
+
         // Test error reporting
         assert_eq!(
             Uuid::parse_str("67e5504410b1426f9247bb680e5fe0c")
@@ -422,6 +657,7 @@ mod tests {
                 expected: EXPECTED_CHARS,
                 found: 'X',
                 index: 6,
+                start: 0,
                 urn: error::UrnPrefix::Optional,
             })
         );
@@ -443,5 +679,264 @@ mod tests {
                 group: 3,
             })
         );
+
+        // Indexes count from the start of the UUID, after any prefix.
+        assert_eq!(
+            Uuid::parse_str("urn:uuid:67e55044-10b1-426f-9247-bb680e5fe0cX")
+                .map_err(crate::Error::expect_parser),
+            Err(Error::InvalidCharacter {
+                expected: EXPECTED_CHARS,
+                found: 'X',
+                index: 35,
+                start: 9,
+                urn: error::UrnPrefix::Optional,
+            })
+        );
+        assert_eq!(
+            Uuid::parse_str("{X7e55044-10b1-426f-9247-bb680e5fe0c8}")
+                .map_err(crate::Error::expect_parser),
+            Err(Error::InvalidCharacter {
+                expected: EXPECTED_CHARS,
+                found: 'X',
+                index: 0,
+                start: 1,
+                urn: error::UrnPrefix::NotApplicable,
+            })
+        );
+
+        // Braced input is only hyphenated, and must have both braces.
+        assert_eq!(
+            Uuid::parse_str("{67e5504410b1426f9247bb680e5fe0c8abcd}")
+                .map_err(crate::Error::expect_parser),
+            Err(Error::InvalidGroupCount {
+                expected: error::ExpectedLength::Exact(5),
+                found: 1,
+            })
+        );
+        assert_eq!(
+            Uuid::parse_str("67e55044-10b1-426f-9247-bb680e5fe0c8}}")
+                .map_err(crate::Error::expect_parser),
+            Err(Error::InvalidCharacter {
+                expected: "{",
+                found: '6',
+                index: 0,
+                start: 0,
+                urn: error::UrnPrefix::NotApplicable,
+            })
+        );
+        assert_eq!(
+            Uuid::parse_str("{67e55044-10b1-426f-9247-bb680e5fe0c\u{e9}")
+                .map_err(crate::Error::expect_parser),
+            Err(Error::InvalidCharacter {
+                expected: "}",
+                found: '\u{e9}',
+                index: 36,
+                start: 0,
+                urn: error::UrnPrefix::NotApplicable,
+            })
+        );
+        assert_eq!(
+            Uuid::parse_str("{67e55044-10b1-426f-9247-bb680e5fe0c8X")
+                .unwrap_err()
+                .to_string(),
+            "invalid character: expected }, found X at 37"
+        );
+    }
+
+    #[test]
+    fn test_parse_str_const() {
+        for input in &[
+            "67e55044-10b1-426f-9247-bb680e5fe0c8",
+            "67E55044-10B1-426F-9247-BB680E5FE0C8",
+            "67e5504410b1426f9247bb680e5fe0c8",
+            "urn:uuid:67e55044-10b1-426f-9247-bb680e5fe0c8",
+            "{67e55044-10b1-426f-9247-bb680e5fe0c8}",
+            "00000000000000000000000000000000",
+            "ffffffff-ffff-ffff-ffff-ffffffffffff",
+        ] {
+            assert_eq!(
+                Uuid::parse_str_const(input),
+                Uuid::parse_str(input).unwrap(),
+                "{}",
+                input
+            );
+        }
+
+        const NAMESPACE_DNS: Uuid =
+            Uuid::parse_str_const("6ba7b810-9dad-11d1-80b4-00c04fd430c8");
+        assert_eq!(NAMESPACE_DNS, Uuid::NAMESPACE_DNS);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "invalid character: expected an optional prefix of \
+        `urn:uuid:` followed by 0123456789abcdefABCDEF-, found X at 35"
+    )]
+    fn test_parse_str_const_invalid_char() {
+        Uuid::parse_str_const("67e55044-10b1-426f-9247-bb680e5fe0cX");
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "invalid character: expected an optional prefix of \
+        `urn:uuid:` followed by 0123456789abcdefABCDEF-, found _ at 23"
+    )]
+    fn test_parse_str_const_invalid_hyphen() {
+        Uuid::parse_str_const("67e55044-10b1-426f-9247_bb680e5fe0c8");
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "invalid group length: expected 8, found 7 in group 0"
+    )]
+    fn test_parse_str_const_misplaced_hyphen() {
+        Uuid::parse_str_const("67e5504-410b1-426f-9247-bb680e5fe0c8");
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "invalid length: expected one of [36, 32, 38], found 45"
+    )]
+    fn test_parse_str_const_invalid_prefix() {
+        Uuid::parse_str_const("urn:uuud:67e55044-10b1-426f-9247-bb680e5fe0c8");
+    }
+
+    #[test]
+    #[should_panic(expected = "invalid character: expected }, found ] at 37")]
+    fn test_parse_str_const_invalid_braces() {
+        Uuid::parse_str_const("{67e55044-10b1-426f-9247-bb680e5fe0c8]");
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "invalid length: expected one of [36, 32, 38], found 35"
+    )]
+    fn test_parse_str_const_invalid_len() {
+        Uuid::parse_str_const("67e55044-10b1-426f-9247-bb680e5fe0c");
+    }
+
+    #[test]
+    fn test_parse_str_const_parity() {
+        for input in &[
+            "",
+            "!",
+            "00000000000000000000000000000000",
+            "00000000-0000-0000-0000-000000000000",
+            "01020304-1112-2122-3132-41424344",
+            "01020304-1112-2122-3132-414243444546",
+            "231231212212423424324323477343246663",
+            "67e550-4105b1426f9247bb680e5fe0c",
+            "67e55044-10b1-426f-9247-bb680e5fe0c",
+            "67e55044-10b1-426f-9247-bb680e5fe0cX",
+            "67e55044-10b1-426f-9247_bb680e5fe0c8",
+            "67e5504-410b1-426f-9247-bb680e5fe0c8",
+            "67e5504410b1426%9247bb680e5fe0c8",
+            "67e5504410b1426f9247bb680e5fe0c",
+            "67e5504410b1426f9247bb680e5fe0c88",
+            "67e5504410b1426f9247bb680e5fe0cg8",
+            "67e550X410b1426f9247bb680e5fe0cd",
+            "67e5504410b1426f9247bb680e5fe0c8abcd",
+            "67e5504410b1426f9247bb680e5fe0c-",
+            "67e55044-10b1-426f-9247bb680e5fe0c8a",
+            "F9168C5E-CEB-24fa-eB6BFF32-BF39FA1E4",
+            "F9168C5E-CEB2-4faa",
+            "F9168C5E-CEB2-4faa-B6BF-329BF39FA1E4",
+            "F9168C5E-CEB2-4faa-B6BF-329BF39FA1E45",
+            "F9168C5E-CEB2-4faa-B6BF1-02BF39FA1E4",
+            "F9168C5E-CEB2-4faa-B6BFF329BF39FA1E4",
+            "F9168C5E-CEB2-4faa-BBF-329BF39FA1E4",
+            "F9168C5E-CEB2-4faa-BGBF-329BF39FA1E4",
+            "F9168C5E-CEB2-4faaFB6BFF329BF39FA1E4",
+            "F9168C5E-CEB2-4faaXB6BFF329BF39FA1E4",
+            "F9168C5E-CEB2F4faaFB6BFF329BF39FA1E4",
+            "urn:uuid:67e55044-10b1-426f-9247-bb680e5fe0cX",
+            "urn:uuud:67e55044-10b1-426f-9247-bb680e5fe0c8",
+            "{67e55044-10b1-426f-9247-bb680e5fe0c8}",
+            "{67e55044-10b1-426f-9247-bb680e5fe0c8]",
+            "{X7e55044-10b1-426f-9247-bb680e5fe0c8}",
+            "{67e5504410b1426f9247bb680e5fe0c8abcd}",
+            "67e55044-10b1-426f-9247-bb680e5fe0c8}}",
+            "{67e55044-10b1-426f-9247-bb680e5fe0c\u{e9}",
+            "67e55044-10b1-426f-9247-bb680e5fe0c\u{e9}",
+        ] {
+            // What `parse_str_const` would return, or panic with.
+            let result = parse_scalar(input.as_bytes()).map_err(|invalid| {
+                message::Message::new(&invalid, input).as_str().to_string()
+            });
+
+            assert_eq!(
+                result,
+                Uuid::parse_str(input)
+                    .map(|uuid| *uuid.as_bytes())
+                    .map_err(|err| err.to_string()),
+                "{}",
+                input
+            );
+        }
+    }
+
+    #[test]
+    fn test_try_parse_ascii() {
+        for input in &[
+            "67e55044-10b1-426f-9247-bb680e5fe0c8",
+            "67E55044-10B1-426F-9247-BB680E5FE0C8",
+            "67e5504410b1426f9247bb680e5fe0c8",
+            "urn:uuid:67e55044-10b1-426f-9247-bb680e5fe0c8",
+            "{67e55044-10b1-426f-9247-bb680e5fe0c8}",
+            "",
+            "67e5504410b1426f9247bb680e5fe0c",
+            "67e550X410b1426f9247bb680e5fe0cd",
+            "67e550-4105b1426f9247bb680e5fe0c",
+            "F9168C5E-CEB2-4faa-B6BF1-02BF39FA1E4",
+            "67e5504410b1426f9247bb680e5fe0c8abcd",
+            "urn:uuid:67e55044-10b1-426f-9247-bb680e5fe0cX",
+        ] {
+            assert_eq!(
+                Uuid::try_parse_ascii(input.as_bytes()),
+                Uuid::parse_str(input),
+                "{}",
+                input
+            );
+        }
+    }
+
+    #[test]
+    fn test_try_parse_ascii_non_ascii() {
+        let input = "67e55044-10b1-426f-9247-bb680e5fe0\u{e9}";
+
+        assert_eq!(
+            Uuid::parse_str(input).map_err(crate::Error::expect_parser),
+            Err(Error::InvalidCharacter {
+                expected: "0123456789abcdefABCDEF-",
+                found: '\u{e9}',
+                index: 34,
+                start: 0,
+                urn: error::UrnPrefix::Optional,
+            })
+        );
+        assert_eq!(
+            Uuid::try_parse_ascii(input.as_bytes())
+                .map_err(crate::Error::expect_parser),
+            Err(Error::InvalidCharacter {
+                expected: "0123456789abcdefABCDEF-",
+                found: crate::std::char::REPLACEMENT_CHARACTER,
+                index: 34,
+                start: 0,
+                urn: error::UrnPrefix::Optional,
+            })
+        );
+
+        // Not valid UTF-8.
+        assert_eq!(
+            Uuid::try_parse_ascii(b"{67e55044-10b1-426f-9247-\xffb680e5fe0c8}")
+                .map_err(crate::Error::expect_parser),
+            Err(Error::InvalidCharacter {
+                expected: "0123456789abcdefABCDEF-",
+                found: crate::std::char::REPLACEMENT_CHARACTER,
+                index: 24,
+                start: 1,
+                urn: error::UrnPrefix::NotApplicable,
+            })
+        );
     }
 }
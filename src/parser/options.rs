@@ -0,0 +1,365 @@
+use super::{parse, Error};
+use crate::Uuid;
+
+/// Leniencies to allow when parsing a [`Uuid`] with [`Uuid::parse_with`].
+///
+/// By default, no leniencies are enabled and [`Uuid::parse_with`] accepts
+/// exactly what [`Uuid::parse_str`] does: the simple, hyphenated, urn and
+/// braced formats. Each leniency can then be enabled on its own.
+///
+/// # Examples
+///
+/// ```
+/// use uuid::{ParseOptions, Uuid};
+///
+/// let options = ParseOptions::new().trim_whitespace(true).strip_quotes(true);
+///
+/// let uuid = Uuid::parse_with(
+///     " \"67e55044-10b1-426f-9247-bb680e5fe0c8\"\n",
+///     &options,
+/// )?;
+///
+/// assert_eq!(
+///     uuid,
+///     Uuid::parse_str("67e55044-10b1-426f-9247-bb680e5fe0c8")?
+/// );
+/// # Ok::<(), uuid::Error>(())
+/// ```
+///
+/// [`Uuid`]: struct.Uuid.html
+/// [`Uuid::parse_with`]: struct.Uuid.html#method.parse_with
+/// [`Uuid::parse_str`]: struct.Uuid.html#method.parse_str
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub struct ParseOptions {
+    trim_whitespace: bool,
+    strip_quotes: bool,
+    braces: bool,
+    urn_prefix_any_case: bool,
+}
+
+impl ParseOptions {
+    /// Creates options with no leniencies enabled.
+    pub const fn new() -> Self {
+        ParseOptions {
+            trim_whitespace: false,
+            strip_quotes: false,
+            braces: false,
+            urn_prefix_any_case: false,
+        }
+    }
+
+    /// Creates options with every leniency enabled.
+    pub const fn lenient() -> Self {
+        ParseOptions {
+            trim_whitespace: true,
+            strip_quotes: true,
+            braces: true,
+            urn_prefix_any_case: true,
+        }
+    }
+
+    /// Sets whether leading and trailing whitespace is ignored.
+    ///
+    /// Whitespace is trimmed before anything else, so it can surround quotes
+    /// but isn't removed from inside them.
+    ///
+    /// ```
+    /// use uuid::{ParseOptions, Uuid};
+    ///
+    /// let options = ParseOptions::new().trim_whitespace(true);
+    ///
+    /// assert!(Uuid::parse_with(
+    ///     "\t67e5504410b1426f9247bb680e5fe0c8 ",
+    ///     &options
+    /// )
+    /// .is_ok());
+    /// ```
+    pub const fn trim_whitespace(self, enabled: bool) -> Self {
+        ParseOptions {
+            trim_whitespace: enabled,
+            ..self
+        }
+    }
+
+    /// Sets whether a pair of matching double (`"`) or single (`'`) quotes
+    /// around the UUID is ignored.
+    ///
+    /// ```
+    /// use uuid::{ParseOptions, Uuid};
+    ///
+    /// let options = ParseOptions::new().strip_quotes(true);
+    ///
+    /// assert!(Uuid::parse_with(
+    ///     "'67e5504410b1426f9247bb680e5fe0c8'",
+    ///     &options
+    /// )
+    /// .is_ok());
+    /// assert!(Uuid::parse_with(
+    ///     "'67e5504410b1426f9247bb680e5fe0c8\"",
+    ///     &options
+    /// )
+    /// .is_err());
+    /// ```
+    pub const fn strip_quotes(self, enabled: bool) -> Self {
+        ParseOptions {
+            strip_quotes: enabled,
+            ..self
+        }
+    }
+
+    /// Sets whether braces are accepted around any format, rather than only
+    /// around the hyphenated format.
+    ///
+    /// ```
+    /// use uuid::{ParseOptions, Uuid};
+    ///
+    /// let options = ParseOptions::new().braces(true);
+    ///
+    /// assert!(Uuid::parse_with(
+    ///     "{67e5504410b1426f9247bb680e5fe0c8}",
+    ///     &options
+    /// )
+    /// .is_ok());
+    /// ```
+    pub const fn braces(self, enabled: bool) -> Self {
+        ParseOptions {
+            braces: enabled,
+            ..self
+        }
+    }
+
+    /// Sets whether the `urn:uuid:` prefix is accepted in any case, such as
+    /// `URN:UUID:`, and in front of any format rather than only the
+    /// hyphenated format.
+    ///
+    /// ```
+    /// use uuid::{ParseOptions, Uuid};
+    ///
+    /// let options = ParseOptions::new().urn_prefix_any_case(true);
+    ///
+    /// assert!(Uuid::parse_with(
+    ///     "URN:UUID:67e55044-10b1-426f-9247-bb680e5fe0c8",
+    ///     &options
+    /// )
+    /// .is_ok());
+    /// ```
+    pub const fn urn_prefix_any_case(self, enabled: bool) -> Self {
+        ParseOptions {
+            urn_prefix_any_case: enabled,
+            ..self
+        }
+    }
+}
+
+impl Uuid {
+    /// Parses a `Uuid` from a string, allowing the leniencies enabled in
+    /// `options`.
+    ///
+    /// Leniencies are applied from the outside in: whitespace is trimmed,
+    /// then quotes, braces and the `urn:uuid:` prefix are removed, and what
+    /// remains is parsed like [`parse_str`]. Indexes in errors are relative
+    /// to the original `input`.
+    ///
+    /// See [`ParseOptions`] for the leniencies that can be enabled.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use uuid::{ParseOptions, Uuid};
+    ///
+    /// let uuid = Uuid::parse_with(
+    ///     "  \"{URN:UUID:67E5504410B1426F9247BB680E5FE0C8}\"",
+    ///     &ParseOptions::lenient(),
+    /// )?;
+    ///
+    /// assert_eq!(uuid, Uuid::parse_str("67e5504410b1426f9247bb680e5fe0c8")?);
+    /// # Ok::<(), uuid::Error>(())
+    /// ```
+    ///
+    /// [`parse_str`]: #method.parse_str
+    /// [`ParseOptions`]: struct.ParseOptions.html
+    pub fn parse_with(
+        input: &str,
+        options: &ParseOptions,
+    ) -> Result<Uuid, crate::Error> {
+        let mut offset = 0;
+        let mut input = input;
+
+        if options.trim_whitespace {
+            let trimmed = input.trim_start();
+            offset += input.len() - trimmed.len();
+            input = trimmed.trim_end();
+        }
+
+        if options.strip_quotes {
+            let bytes = input.as_bytes();
+            if bytes.len() >= 2
+                && (bytes[0] == b'"' || bytes[0] == b'\'')
+                && bytes[bytes.len() - 1] == bytes[0]
+            {
+                input = &input[1..input.len() - 1];
+                offset += 1;
+            }
+        }
+
+        if options.braces && input.starts_with('{') && input.ends_with('}') {
+            input = &input[1..input.len() - 1];
+            offset += 1;
+        }
+
+        if options.urn_prefix_any_case
+            && input.len() >= 9
+            && input.is_char_boundary(9)
+            && input[..9].eq_ignore_ascii_case("urn:uuid:")
+        {
+            input = &input[9..];
+            offset += 9;
+        }
+
+        parse(input).map_err(|err: Error| err.offset(offset).into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parser::error;
+
+    const UUID: &str = "67e55044-10b1-426f-9247-bb680e5fe0c8";
+
+    fn expected() -> Uuid {
+        Uuid::parse_str(UUID).unwrap()
+    }
+
+    #[test]
+    fn test_default_matches_parse_str() {
+        let options = ParseOptions::default();
+
+        assert_eq!(options, ParseOptions::new());
+
+        for input in &[
+            UUID,
+            "67e5504410b1426f9247bb680e5fe0c8",
+            "urn:uuid:67e55044-10b1-426f-9247-bb680e5fe0c8",
+            "{67e55044-10b1-426f-9247-bb680e5fe0c8}",
+            " 67e55044-10b1-426f-9247-bb680e5fe0c8",
+            "\"67e55044-10b1-426f-9247-bb680e5fe0c8\"",
+            "{67e5504410b1426f9247bb680e5fe0c8}",
+            "URN:UUID:67e55044-10b1-426f-9247-bb680e5fe0c8",
+        ] {
+            assert_eq!(
+                Uuid::parse_with(input, &options),
+                Uuid::parse_str(input),
+                "{}",
+                input
+            );
+        }
+    }
+
+    #[test]
+    fn test_trim_whitespace() {
+        let options = ParseOptions::new().trim_whitespace(true);
+
+        for input in &[
+            " 67e55044-10b1-426f-9247-bb680e5fe0c8",
+            "67e55044-10b1-426f-9247-bb680e5fe0c8\r\n",
+            "\t 67e55044-10b1-426f-9247-bb680e5fe0c8 \u{a0}",
+        ] {
+            assert_eq!(Uuid::parse_with(input, &options), Ok(expected()));
+        }
+
+        assert!(Uuid::parse_with(
+            "\" 67e55044-10b1-426f-9247-bb680e5fe0c8\"",
+            &options
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn test_strip_quotes() {
+        let options = ParseOptions::new().strip_quotes(true);
+
+        for input in &[
+            "\"67e55044-10b1-426f-9247-bb680e5fe0c8\"",
+            "'67e55044-10b1-426f-9247-bb680e5fe0c8'",
+        ] {
+            assert_eq!(Uuid::parse_with(input, &options), Ok(expected()));
+        }
+
+        for input in &[
+            "\"67e55044-10b1-426f-9247-bb680e5fe0c8'",
+            "\"67e55044-10b1-426f-9247-bb680e5fe0c8",
+            " \"67e55044-10b1-426f-9247-bb680e5fe0c8\"",
+        ] {
+            assert!(Uuid::parse_with(input, &options).is_err(), "{}", input);
+        }
+    }
+
+    #[test]
+    fn test_braces() {
+        let options = ParseOptions::new().braces(true);
+
+        for input in &[
+            "{67e55044-10b1-426f-9247-bb680e5fe0c8}",
+            "{67e5504410b1426f9247bb680e5fe0c8}",
+            "{urn:uuid:67e55044-10b1-426f-9247-bb680e5fe0c8}",
+        ] {
+            assert_eq!(Uuid::parse_with(input, &options), Ok(expected()));
+        }
+
+        assert!(Uuid::parse_with(
+            "{67e5504410b1426f9247bb680e5fe0c8",
+            &options
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn test_urn_prefix_any_case() {
+        let options = ParseOptions::new().urn_prefix_any_case(true);
+
+        for input in &[
+            "URN:UUID:67e55044-10b1-426f-9247-bb680e5fe0c8",
+            "Urn:Uuid:67e55044-10b1-426f-9247-bb680e5fe0c8",
+            "urn:uuid:67e5504410b1426f9247bb680e5fe0c8",
+        ] {
+            assert_eq!(Uuid::parse_with(input, &options), Ok(expected()));
+        }
+
+        assert!(Uuid::parse_with("URN:UUID:", &options).is_err());
+        assert!(Uuid::parse_with("urn:uuíd:", &options).is_err());
+    }
+
+    #[test]
+    fn test_lenient() {
+        let options = ParseOptions::lenient();
+
+        for input in &[
+            UUID,
+            " '{URN:UUID:67E5504410B1426F9247BB680E5FE0C8}' ",
+            "\"urn:uuid:67e55044-10b1-426f-9247-bb680e5fe0c8\"",
+        ] {
+            assert_eq!(Uuid::parse_with(input, &options), Ok(expected()));
+        }
+    }
+
+    #[test]
+    fn test_error_index() {
+        let options = ParseOptions::lenient();
+
+        assert_eq!(
+            Uuid::parse_with(
+                " \"{urn:uuid:67e55044-10b1-426f-9247-bb680e5fe0cX}\"",
+                &options
+            )
+            .map_err(crate::Error::expect_parser),
+            Err(Error::InvalidCharacter {
+                expected: "0123456789abcdefABCDEF-",
+                found: 'X',
+                index: 47,
+                start: 0,
+                urn: error::UrnPrefix::Optional,
+            })
+        );
+    }
+}
@@ -0,0 +1,328 @@
+//! Vectorized parsing of the simple and hyphenated formats.
+//!
+//! The vectorized paths only ever accept input, they never describe what's
+//! wrong with it. Any input they can't parse is handed to the scalar parser,
+//! which reports the same errors as it always has.
+
+use crate::Uuid;
+
+/// Parses a simple or hyphenated `input` using vector instructions.
+///
+/// Returns `None` if `input` isn't valid, or if no vector instructions are
+/// available for the target.
+pub(super) fn parse(input: &[u8]) -> Option<Uuid> {
+    let mut digits = [0u8; 32];
+
+    match input.len() {
+        32 => digits.copy_from_slice(input),
+        36 => {
+            if input[8] != b'-'
+                || input[13] != b'-'
+                || input[18] != b'-'
+                || input[23] != b'-'
+            {
+                return None;
+            }
+
+            digits[..8].copy_from_slice(&input[..8]);
+            digits[8..12].copy_from_slice(&input[9..13]);
+            digits[12..16].copy_from_slice(&input[14..18]);
+            digits[16..20].copy_from_slice(&input[19..23]);
+            digits[20..].copy_from_slice(&input[24..]);
+        }
+        _ => return None,
+    }
+
+    decode(&digits).map(Uuid::from_bytes)
+}
+
+/// Decodes 32 hex digits, in any case, into 16 bytes.
+fn decode(digits: &[u8; 32]) -> Option<[u8; 16]> {
+    #[cfg(all(
+        feature = "std",
+        any(target_arch = "x86", target_arch = "x86_64")
+    ))]
+    {
+        if is_x86_feature_detected!("avx2") {
+            // SAFETY: AVX2 is available on this CPU.
+            return unsafe { x86::decode_avx2(digits) };
+        }
+    }
+
+    decode_baseline(digits)
+}
+
+#[cfg(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    target_feature = "sse2"
+))]
+fn decode_baseline(digits: &[u8; 32]) -> Option<[u8; 16]> {
+    // SAFETY: SSE2 is enabled for the target.
+    unsafe { x86::decode_sse2(digits) }
+}
+
+#[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
+fn decode_baseline(digits: &[u8; 32]) -> Option<[u8; 16]> {
+    // SAFETY: NEON is enabled for the target.
+    unsafe { aarch64::decode_neon(digits) }
+}
+
+#[cfg(not(any(
+    all(
+        any(target_arch = "x86", target_arch = "x86_64"),
+        target_feature = "sse2"
+    ),
+    all(target_arch = "aarch64", target_feature = "neon")
+)))]
+fn decode_baseline(_: &[u8; 32]) -> Option<[u8; 16]> {
+    None
+}
+
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+mod x86 {
+    #[cfg(target_arch = "x86")]
+    use crate::std::arch::x86::*;
+    #[cfg(target_arch = "x86_64")]
+    use crate::std::arch::x86_64::*;
+
+    /// Converts each hex digit in `chars` to its value, along with a mask
+    /// of the lanes that held a hex digit.
+    #[target_feature(enable = "sse2")]
+    unsafe fn hex_values_sse2(chars: __m128i) -> (__m128i, __m128i) {
+        let zero = _mm_setzero_si128();
+
+        // Bytes that aren't digits wrap around to negative values, or are
+        // past `9`.
+        let digit = _mm_sub_epi8(chars, _mm_set1_epi8(b'0' as i8));
+        let is_digit = _mm_andnot_si128(
+            _mm_cmplt_epi8(digit, zero),
+            _mm_cmplt_epi8(digit, _mm_set1_epi8(10)),
+        );
+
+        // Setting the `0x20` bit maps upper-case letters to lower-case.
+        let letter = _mm_sub_epi8(
+            _mm_or_si128(chars, _mm_set1_epi8(0x20)),
+            _mm_set1_epi8(b'a' as i8),
+        );
+        let is_letter = _mm_andnot_si128(
+            _mm_cmplt_epi8(letter, zero),
+            _mm_cmplt_epi8(letter, _mm_set1_epi8(6)),
+        );
+
+        let values = _mm_or_si128(
+            _mm_and_si128(is_digit, digit),
+            _mm_and_si128(is_letter, _mm_add_epi8(letter, _mm_set1_epi8(10))),
+        );
+
+        (values, _mm_or_si128(is_digit, is_letter))
+    }
+
+    /// Combines the two nibbles in each 16-bit lane into a byte in the low
+    /// half of the lane.
+    #[target_feature(enable = "sse2")]
+    unsafe fn combine_sse2(values: __m128i) -> __m128i {
+        let high =
+            _mm_slli_epi16(_mm_and_si128(values, _mm_set1_epi16(0xff)), 4);
+        let low = _mm_srli_epi16(values, 8);
+
+        _mm_or_si128(high, low)
+    }
+
+    #[target_feature(enable = "sse2")]
+    pub(super) unsafe fn decode_sse2(digits: &[u8; 32]) -> Option<[u8; 16]> {
+        let first = _mm_loadu_si128(digits.as_ptr() as *const __m128i);
+        let second = _mm_loadu_si128(digits.as_ptr().add(16) as *const __m128i);
+
+        let (first, first_valid) = hex_values_sse2(first);
+        let (second, second_valid) = hex_values_sse2(second);
+
+        if _mm_movemask_epi8(_mm_and_si128(first_valid, second_valid)) != 0xffff
+        {
+            return None;
+        }
+
+        let bytes = _mm_packus_epi16(combine_sse2(first), combine_sse2(second));
+
+        let mut out = [0u8; 16];
+        _mm_storeu_si128(out.as_mut_ptr() as *mut __m128i, bytes);
+
+        Some(out)
+    }
+
+    #[cfg(feature = "std")]
+    #[target_feature(enable = "avx2")]
+    pub(super) unsafe fn decode_avx2(digits: &[u8; 32]) -> Option<[u8; 16]> {
+        let chars = _mm256_loadu_si256(digits.as_ptr() as *const __m256i);
+        let zero = _mm256_setzero_si256();
+
+        let digit = _mm256_sub_epi8(chars, _mm256_set1_epi8(b'0' as i8));
+        let is_digit = _mm256_andnot_si256(
+            _mm256_cmpgt_epi8(zero, digit),
+            _mm256_cmpgt_epi8(_mm256_set1_epi8(10), digit),
+        );
+
+        let letter = _mm256_sub_epi8(
+            _mm256_or_si256(chars, _mm256_set1_epi8(0x20)),
+            _mm256_set1_epi8(b'a' as i8),
+        );
+        let is_letter = _mm256_andnot_si256(
+            _mm256_cmpgt_epi8(zero, letter),
+            _mm256_cmpgt_epi8(_mm256_set1_epi8(6), letter),
+        );
+
+        if _mm256_movemask_epi8(_mm256_or_si256(is_digit, is_letter)) != -1 {
+            return None;
+        }
+
+        let values = _mm256_or_si256(
+            _mm256_and_si256(is_digit, digit),
+            _mm256_and_si256(
+                is_letter,
+                _mm256_add_epi8(letter, _mm256_set1_epi8(10)),
+            ),
+        );
+
+        let combined = _mm256_or_si256(
+            _mm256_slli_epi16(
+                _mm256_and_si256(values, _mm256_set1_epi16(0xff)),
+                4,
+            ),
+            _mm256_srli_epi16(values, 8),
+        );
+
+        // Packing works within each 128-bit half, so gather the first 64
+        // bits of each half into the low 128 bits.
+        let bytes = _mm256_permute4x64_epi64(
+            _mm256_packus_epi16(combined, combined),
+            0b00_00_10_00,
+        );
+
+        let mut out = [0u8; 16];
+        _mm_storeu_si128(
+            out.as_mut_ptr() as *mut __m128i,
+            _mm256_castsi256_si128(bytes),
+        );
+
+        Some(out)
+    }
+}
+
+#[cfg(target_arch = "aarch64")]
+mod aarch64 {
+    use crate::std::arch::aarch64::*;
+
+    /// Converts each hex digit in `chars` to its value, along with a mask
+    /// of the lanes that held a hex digit.
+    #[target_feature(enable = "neon")]
+    unsafe fn hex_values_neon(chars: uint8x16_t) -> (uint8x16_t, uint8x16_t) {
+        let digit = vsubq_u8(chars, vdupq_n_u8(b'0'));
+        let is_digit = vcltq_u8(digit, vdupq_n_u8(10));
+
+        // Setting the `0x20` bit maps upper-case letters to lower-case.
+        let letter =
+            vsubq_u8(vorrq_u8(chars, vdupq_n_u8(0x20)), vdupq_n_u8(b'a'));
+        let is_letter = vcltq_u8(letter, vdupq_n_u8(6));
+
+        let values =
+            vbslq_u8(is_digit, digit, vaddq_u8(letter, vdupq_n_u8(10)));
+
+        (values, vorrq_u8(is_digit, is_letter))
+    }
+
+    #[target_feature(enable = "neon")]
+    pub(super) unsafe fn decode_neon(digits: &[u8; 32]) -> Option<[u8; 16]> {
+        let (first, first_valid) = hex_values_neon(vld1q_u8(digits.as_ptr()));
+        let (second, second_valid) =
+            hex_values_neon(vld1q_u8(digits.as_ptr().add(16)));
+
+        if vminvq_u8(vandq_u8(first_valid, second_valid)) != 0xff {
+            return None;
+        }
+
+        let high = vuzp1q_u8(first, second);
+        let low = vuzp2q_u8(first, second);
+
+        let mut out = [0u8; 16];
+        vst1q_u8(out.as_mut_ptr(), vorrq_u8(vshlq_n_u8(high, 4), low));
+
+        Some(out)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const HYPHENATED: &[u8] = b"67e55044-10b1-426f-9247-bb680e5fe0c8";
+    const SIMPLE: &[u8] = b"F9168C5ECEB24faaB6BF329BF39FA1E4";
+
+    #[test]
+    fn test_parse_matches_scalar() {
+        for input in &[HYPHENATED, SIMPLE] {
+            let expected = super::super::parse_bytes(input, |_| '?').ok();
+
+            assert!(expected.is_some());
+            if let Some(uuid) = parse(input) {
+                assert_eq!(Some(uuid), expected);
+            }
+
+            // Every possible byte in every position.
+            for index in 0..input.len() {
+                let mut input = input.to_vec();
+
+                for byte in 0..=255 {
+                    input[index] = byte;
+
+                    let expected =
+                        super::super::parse_bytes(&input, |_| '?').ok();
+                    let parsed = parse(&input);
+
+                    assert!(
+                        parsed.is_none() || parsed == expected,
+                        "{:?}",
+                        input
+                    );
+                    if cfg!(any(
+                        target_arch = "x86_64",
+                        target_arch = "aarch64"
+                    )) {
+                        assert_eq!(parsed, expected, "{:?}", input);
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_parse_rejects_misplaced_hyphens() {
+        assert_eq!(parse(b"67e5504-410b1-426f-9247-bb680e5fe0c8"), None);
+        assert_eq!(parse(b"67e55044-10b1-426f-9247-bb680e5fe0c"), None);
+        assert_eq!(parse(b"67e55044-10b1-426f-9247bb680e5fe0c8-"), None);
+    }
+
+    #[cfg(all(feature = "std", target_arch = "x86_64"))]
+    #[test]
+    fn test_decode_sse2_matches_avx2() {
+        if !is_x86_feature_detected!("avx2") {
+            return;
+        }
+
+        let mut digits = [0u8; 32];
+        for (i, digit) in digits.iter_mut().enumerate() {
+            *digit = b"0123456789abcdefABCDEF"[i % 22];
+        }
+
+        for index in 0..digits.len() {
+            for byte in 0..=255 {
+                let mut digits = digits;
+                digits[index] = byte;
+
+                // SAFETY: SSE2 is part of x86_64 and AVX2 was detected.
+                let (sse2, avx2) = unsafe {
+                    (x86::decode_sse2(&digits), x86::decode_avx2(&digits))
+                };
+                assert_eq!(sse2, avx2, "{:?}", &digits[..]);
+            }
+        }
+    }
+}
@@ -0,0 +1,289 @@
+use super::{error, parse, Error};
+use crate::adapter::{Hyphenated, Simple, Urn};
+use crate::std::{convert::TryFrom, str::FromStr};
+use crate::Uuid;
+
+/// Parses `input` as exactly `len` characters of a single format, optionally
+/// rejecting upper-case hex digits.
+fn parse_exact(
+    input: &str,
+    len: usize,
+    prefix: &'static str,
+    lower: bool,
+    expected_lower: &'static str,
+) -> Result<Uuid, Error> {
+    if input.len() != len {
+        return Err(Error::InvalidLength {
+            expected: error::ExpectedLength::Exact(len),
+            found: input.len(),
+        });
+    }
+
+    for (index, (chr, expected)) in
+        input.bytes().zip(prefix.bytes()).enumerate()
+    {
+        if chr != expected {
+            return Err(Error::InvalidCharacter {
+                expected: prefix,
+                found: input[index..].chars().next().unwrap(),
+                index,
+                start: 0,
+                urn: error::UrnPrefix::NotApplicable,
+            });
+        }
+    }
+
+    let uuid = parse(&input[prefix.len()..])
+        .map_err(|err| err.offset(prefix.len()))?;
+
+    if lower {
+        if let Some(index) =
+            input.bytes().position(|chr| chr.is_ascii_uppercase())
+        {
+            return Err(Error::InvalidCharacter {
+                expected: expected_lower,
+                found: input.as_bytes()[index] as char,
+                index,
+                start: 0,
+                urn: error::UrnPrefix::NotApplicable,
+            });
+        }
+    }
+
+    Ok(uuid)
+}
+
+macro_rules! impl_strict_parse {
+    ($(
+        $T:ident {
+            prefix: $prefix:expr,
+            expected_lower: $expected_lower:expr,
+            example: $example:expr,
+        }
+    )+) => {$(
+        impl $T {
+            /// Parses a string in exactly this format, in any case.
+            ///
+            /// Unlike [`Uuid::parse_str`], other formats are rejected. This
+            /// is the same as parsing with [`FromStr`].
+            ///
+            /// [`Uuid::parse_str`]: ../struct.Uuid.html#method.parse_str
+            /// [`FromStr`]: https://doc.rust-lang.org/std/str/trait.FromStr.html
+            pub fn parse_str(input: &str) -> Result<Self, crate::Error> {
+                Ok($T::from_uuid(parse_exact(
+                    input,
+                    $T::LENGTH,
+                    $prefix,
+                    false,
+                    $expected_lower,
+                )?))
+            }
+
+            /// Parses a string in exactly this format, rejecting upper-case
+            /// hex digits.
+            ///
+            /// This is useful to check that stored values are in a
+            /// canonical form.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            #[doc = concat!("use uuid::adapter::", stringify!($T), ";")]
+            ///
+            #[doc = concat!("let input = \"", $example, "\";")]
+            ///
+            #[doc = concat!(
+                "assert!(",
+                stringify!($T),
+                "::parse_lower(input).is_ok());"
+            )]
+            #[doc = concat!(
+                "assert!(",
+                stringify!($T),
+                "::parse_lower(&input.to_uppercase()).is_err());"
+            )]
+            /// ```
+            pub fn parse_lower(input: &str) -> Result<Self, crate::Error> {
+                Ok($T::from_uuid(parse_exact(
+                    input,
+                    $T::LENGTH,
+                    $prefix,
+                    true,
+                    $expected_lower,
+                )?))
+            }
+        }
+
+        impl FromStr for $T {
+            type Err = crate::Error;
+
+            fn from_str(input: &str) -> Result<Self, Self::Err> {
+                $T::parse_str(input)
+            }
+        }
+
+        impl<'a> TryFrom<&'a str> for $T {
+            type Error = crate::Error;
+
+            fn try_from(input: &'a str) -> Result<Self, Self::Error> {
+                $T::parse_str(input)
+            }
+        }
+    )+}
+}
+
+impl_strict_parse! {
+    Hyphenated {
+        prefix: "",
+        expected_lower: "0123456789abcdef-",
+        example: "67e55044-10b1-426f-9247-bb680e5fe0c8",
+    }
+    Simple {
+        prefix: "",
+        expected_lower: "0123456789abcdef",
+        example: "67e5504410b1426f9247bb680e5fe0c8",
+    }
+    Urn {
+        prefix: "urn:uuid:",
+        expected_lower: "0123456789abcdef-",
+        example: "urn:uuid:67e55044-10b1-426f-9247-bb680e5fe0c8",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const HYPHENATED: &str = "67e55044-10b1-426f-9247-bb680e5fe0c8";
+    const SIMPLE: &str = "67e5504410b1426f9247bb680e5fe0c8";
+    const URN: &str = "urn:uuid:67e55044-10b1-426f-9247-bb680e5fe0c8";
+    const BRACED: &str = "{67e55044-10b1-426f-9247-bb680e5fe0c8}";
+
+    fn expected() -> Uuid {
+        Uuid::parse_str(HYPHENATED).unwrap()
+    }
+
+    #[test]
+    fn test_hyphenated() {
+        assert_eq!(
+            HYPHENATED.parse::<Hyphenated>().map(Uuid::from),
+            Ok(expected())
+        );
+        assert_eq!(
+            Hyphenated::try_from(HYPHENATED.to_uppercase().as_str())
+                .map(Uuid::from),
+            Ok(expected())
+        );
+
+        for input in &[SIMPLE, URN, BRACED] {
+            assert_eq!(
+                input
+                    .parse::<Hyphenated>()
+                    .map_err(crate::Error::expect_parser),
+                Err(Error::InvalidLength {
+                    expected: error::ExpectedLength::Exact(36),
+                    found: input.len(),
+                })
+            );
+        }
+
+        assert_eq!(
+            "67e5504410b1426f9247bb680e5fe0c8abcd"
+                .parse::<Hyphenated>()
+                .map_err(crate::Error::expect_parser),
+            Err(Error::InvalidLength {
+                expected: error::ExpectedLength::Any(&[36, 32, 38]),
+                found: 36,
+            })
+        );
+    }
+
+    #[test]
+    fn test_simple() {
+        assert_eq!(SIMPLE.parse::<Simple>().map(Uuid::from), Ok(expected()));
+
+        for input in &[HYPHENATED, URN, BRACED] {
+            assert_eq!(
+                input.parse::<Simple>().map_err(crate::Error::expect_parser),
+                Err(Error::InvalidLength {
+                    expected: error::ExpectedLength::Exact(32),
+                    found: input.len(),
+                })
+            );
+        }
+    }
+
+    #[test]
+    fn test_urn() {
+        assert_eq!(URN.parse::<Urn>().map(Uuid::from), Ok(expected()));
+
+        for input in &[HYPHENATED, SIMPLE, BRACED] {
+            assert_eq!(
+                input.parse::<Urn>().map_err(crate::Error::expect_parser),
+                Err(Error::InvalidLength {
+                    expected: error::ExpectedLength::Exact(45),
+                    found: input.len(),
+                })
+            );
+        }
+
+        assert_eq!(
+            "URN:uuid:67e55044-10b1-426f-9247-bb680e5fe0c8"
+                .parse::<Urn>()
+                .map_err(crate::Error::expect_parser),
+            Err(Error::InvalidCharacter {
+                expected: "urn:uuid:",
+                found: 'U',
+                index: 0,
+                start: 0,
+                urn: error::UrnPrefix::NotApplicable,
+            })
+        );
+        assert_eq!(
+            "urn:uuid:67e55044-10b1-426f-9247-bb680e5fe0cX"
+                .parse::<Urn>()
+                .map_err(crate::Error::expect_parser),
+            Err(Error::InvalidCharacter {
+                expected: "0123456789abcdefABCDEF-",
+                found: 'X',
+                index: 44,
+                start: 0,
+                urn: error::UrnPrefix::Optional,
+            })
+        );
+    }
+
+    #[test]
+    fn test_parse_lower() {
+        assert!(Hyphenated::parse_lower(HYPHENATED).is_ok());
+        assert!(Simple::parse_lower(SIMPLE).is_ok());
+        assert!(Urn::parse_lower(URN).is_ok());
+
+        assert_eq!(
+            Hyphenated::parse_lower("67e55044-10b1-426F-9247-bb680e5fe0c8")
+                .map_err(crate::Error::expect_parser),
+            Err(Error::InvalidCharacter {
+                expected: "0123456789abcdef-",
+                found: 'F',
+                index: 17,
+                start: 0,
+                urn: error::UrnPrefix::NotApplicable,
+            })
+        );
+        assert_eq!(
+            Simple::parse_lower("67e5504410b1426f9247bb680e5fe0C8")
+                .map_err(crate::Error::expect_parser),
+            Err(Error::InvalidCharacter {
+                expected: "0123456789abcdef",
+                found: 'C',
+                index: 30,
+                start: 0,
+                urn: error::UrnPrefix::NotApplicable,
+            })
+        );
+        assert!(Urn::parse_lower(
+            "urn:uuid:67E55044-10b1-426f-9247-bb680e5fe0c8"
+        )
+        .is_err());
+    }
+}
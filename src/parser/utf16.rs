@@ -0,0 +1,137 @@
+use super::{parse_bytes, Error, EXPECTED_LENGTHS};
+use crate::std::char;
+use crate::{adapter, Uuid};
+
+impl Uuid {
+    /// Parses a `Uuid` from a UTF-16 string.
+    ///
+    /// This accepts the same formats and returns the same errors as
+    /// [`parse_str`], so wide strings, such as those read from Windows APIs
+    /// and file formats, don't need to be converted to a `String` first.
+    /// Indexes and lengths in errors are counted in UTF-16 code units.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use uuid::Uuid;
+    ///
+    /// let input: Vec<u16> = "{67e55044-10b1-426f-9247-bb680e5fe0c8}"
+    ///     .encode_utf16()
+    ///     .collect();
+    ///
+    /// assert_eq!(
+    ///     Uuid::parse_utf16(&input)?,
+    ///     Uuid::parse_str("67e55044-10b1-426f-9247-bb680e5fe0c8")?
+    /// );
+    /// # Ok::<(), uuid::Error>(())
+    /// ```
+    ///
+    /// [`parse_str`]: #method.parse_str
+    pub fn parse_utf16(input: &[u16]) -> Result<Uuid, crate::Error> {
+        // Every supported format is at most as long as the urn format, so
+        // longer input can be rejected without narrowing it.
+        if input.len() > adapter::Urn::LENGTH {
+            Err(Error::InvalidLength {
+                expected: EXPECTED_LENGTHS,
+                found: input.len(),
+            })?;
+        }
+
+        // Narrow the input to one byte per code unit. Units that aren't
+        // ASCII become a byte that's never valid, so they're reported as
+        // invalid characters.
+        let mut buffer = [0u8; adapter::Urn::LENGTH];
+        let narrowed = &mut buffer[..input.len()];
+        for (byte, &unit) in narrowed.iter_mut().zip(input) {
+            *byte = if unit < 0x80 { unit as u8 } else { 0xff };
+        }
+
+        Ok(parse_bytes(narrowed, |index| {
+            char::decode_utf16(input[index..].iter().cloned())
+                .next()
+                .unwrap()
+                .unwrap_or(char::REPLACEMENT_CHARACTER)
+        })?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parser::error;
+    use crate::std::vec::Vec;
+
+    fn utf16(s: &str) -> Vec<u16> {
+        s.encode_utf16().collect()
+    }
+
+    #[test]
+    fn test_parse_utf16() {
+        for input in &[
+            "67e55044-10b1-426f-9247-bb680e5fe0c8",
+            "67E55044-10B1-426F-9247-BB680E5FE0C8",
+            "67e5504410b1426f9247bb680e5fe0c8",
+            "urn:uuid:67e55044-10b1-426f-9247-bb680e5fe0c8",
+            "{67e55044-10b1-426f-9247-bb680e5fe0c8}",
+            "",
+            "67e5504410b1426f9247bb680e5fe0c",
+            "67e550X410b1426f9247bb680e5fe0cd",
+            "67e550-4105b1426f9247bb680e5fe0c",
+            "F9168C5E-CEB2-4faa-B6BF1-02BF39FA1E4",
+            "{67e55044-10b1-426f-9247-bb680e5fe0c8}}}}}}}}}}",
+        ] {
+            assert_eq!(
+                Uuid::parse_utf16(&utf16(input)),
+                Uuid::parse_str(input),
+                "{}",
+                input
+            );
+        }
+    }
+
+    #[test]
+    fn test_parse_utf16_non_ascii() {
+        // One code unit, but three bytes in UTF-8.
+        let input = utf16("67e55044-10b1-426f-9247-bb680e5fe0\u{20ac}8");
+
+        assert_eq!(
+            Uuid::parse_utf16(&input).map_err(crate::Error::expect_parser),
+            Err(Error::InvalidCharacter {
+                expected: "0123456789abcdefABCDEF-",
+                found: '\u{20ac}',
+                index: 34,
+                start: 0,
+                urn: error::UrnPrefix::Optional,
+            })
+        );
+
+        // A surrogate pair is two code units.
+        let input = utf16("67e55044-10b1-426f-9247-bb680e5fe\u{1f600}8");
+
+        assert_eq!(
+            Uuid::parse_utf16(&input).map_err(crate::Error::expect_parser),
+            Err(Error::InvalidCharacter {
+                expected: "0123456789abcdefABCDEF-",
+                found: '\u{1f600}',
+                index: 33,
+                start: 0,
+                urn: error::UrnPrefix::Optional,
+            })
+        );
+
+        // An unpaired surrogate.
+        let mut input = utf16("67e55044-10b1-426f-9247-bb680e5fe0c8");
+        input[0] = 0xd800;
+
+        assert_eq!(
+            Uuid::parse_utf16(&input).map_err(crate::Error::expect_parser),
+            Err(Error::InvalidCharacter {
+                expected: "0123456789abcdefABCDEF-",
+                found: char::REPLACEMENT_CHARACTER,
+                index: 0,
+                start: 0,
+                urn: error::UrnPrefix::Optional,
+            })
+        );
+    }
+}
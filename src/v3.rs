@@ -1,5 +1,4 @@
 use crate::prelude::*;
-use md5;
 
 impl Uuid {
     /// Creates a UUID using a name from a namespace, based on the MD5
@@ -13,28 +12,42 @@ impl Uuid {
     /// * [`NAMESPACE_X500`]
     ///
     /// Note that usage of this method requires the `v3` feature of this crate
-    /// to be enabled.
+    /// to be enabled. Enabling the `v3-digest` feature instead computes the
+    /// hash with the RustCrypto [`md-5`] crate.
     ///
+    /// [`md-5`]: https://crates.io/crates/md-5
     /// [`NAMESPACE_DNS`]: #associatedconstant.NAMESPACE_DNS
     /// [`NAMESPACE_OID`]: #associatedconstant.NAMESPACE_OID
     /// [`NAMESPACE_URL`]: #associatedconstant.NAMESPACE_URL
     /// [`NAMESPACE_X500`]: #associatedconstant.NAMESPACE_X500
     pub fn new_v3(namespace: &Uuid, name: &[u8]) -> Uuid {
-        let mut context = md5::Context::new();
+        #[cfg(feature = "v3-digest")]
+        {
+            Uuid::new_name_based::<md5_digest::Md5>(
+                Version::Md5,
+                namespace,
+                name,
+            )
+        }
+
+        #[cfg(not(feature = "v3-digest"))]
+        {
+            let mut context = md5::Context::new();
 
-        context.consume(namespace.as_bytes());
-        context.consume(name);
+            context.consume(namespace.as_bytes());
+            context.consume(name);
 
-        let computed = context.compute();
-        let bytes = computed.into();
+            let computed = context.compute();
+            let bytes = computed.into();
 
-        let mut builder = crate::Builder::from_bytes(bytes);
+            let mut builder = crate::Builder::from_bytes(bytes);
 
-        builder
-            .set_variant(Variant::RFC4122)
-            .set_version(Version::Md5);
+            builder
+                .set_variant(Variant::RFC4122)
+                .set_version(Version::Md5);
 
-        builder.build()
+            builder.build()
+        }
     }
 }
 
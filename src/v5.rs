@@ -1,5 +1,4 @@
 use crate::prelude::*;
-use sha1;
 
 impl Uuid {
     /// Creates a UUID using a name from a namespace, based on the SHA-1 hash.
@@ -12,29 +11,125 @@ impl Uuid {
     /// * [`NAMESPACE_X500`]
     ///
     /// Note that usage of this method requires the `v5` feature of this crate
-    /// to be enabled.
+    /// to be enabled. Enabling the `v5-digest` feature instead computes the
+    /// hash with the RustCrypto [`sha1`] crate.
+    ///
+    /// If SHA-1 is not acceptable, [`new_v8_sha256`] generates name-based
+    /// UUIDs with the same inputs using SHA-256 instead.
     ///
+    /// [`sha1`]: https://crates.io/crates/sha1
+    /// [`new_v8_sha256`]: struct.Uuid.html#method.new_v8_sha256
     /// [`NAMESPACE_DNS`]: struct.Uuid.html#associatedconst.NAMESPACE_DNS
     /// [`NAMESPACE_OID`]: struct.Uuid.html#associatedconst.NAMESPACE_OID
     /// [`NAMESPACE_URL`]: struct.Uuid.html#associatedconst.NAMESPACE_URL
     /// [`NAMESPACE_X500`]: struct.Uuid.html#associatedconst.NAMESPACE_X500
     pub fn new_v5(namespace: &Uuid, name: &[u8]) -> Uuid {
-        let mut hash = sha1::Sha1::new();
+        #[cfg(feature = "v5-digest")]
+        {
+            Uuid::new_name_based::<sha1_digest::Sha1>(
+                Version::Sha1,
+                namespace,
+                name,
+            )
+        }
+
+        #[cfg(not(feature = "v5-digest"))]
+        {
+            let mut hash = sha1::Sha1::new();
 
-        hash.update(namespace.as_bytes());
-        hash.update(name);
+            hash.update(namespace.as_bytes());
+            hash.update(name);
 
-        let buffer = hash.digest().bytes();
+            let buffer = hash.digest().bytes();
 
-        let mut bytes = crate::Bytes::default();
-        bytes.copy_from_slice(&buffer[..16]);
+            let mut bytes = crate::Bytes::default();
+            bytes.copy_from_slice(&buffer[..16]);
 
-        let mut builder = crate::Builder::from_bytes(bytes);
-        builder
-            .set_variant(Variant::RFC4122)
-            .set_version(Version::Sha1);
+            let mut builder = crate::Builder::from_bytes(bytes);
+            builder
+                .set_variant(Variant::RFC4122)
+                .set_version(Version::Sha1);
+
+            builder.build()
+        }
+    }
+
+    /// Derives a child UUID from this UUID and a name.
+    ///
+    /// This is a version 5 UUID generated with `self` as the namespace, so
+    /// the same parent and name always produce the same child. Derived
+    /// UUIDs can be used as parents themselves to model a hierarchy, see
+    /// [`derive_path`].
+    ///
+    /// Note that usage of this method requires the `v5` feature of this crate
+    /// to be enabled.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use uuid::Uuid;
+    ///
+    /// let tenant = Uuid::new_v5(&Uuid::NAMESPACE_DNS, b"example.org");
+    /// let project = tenant.derive(b"billing");
+    ///
+    /// assert_eq!(project, Uuid::new_v5(&tenant, b"billing"));
+    /// ```
+    ///
+    /// [`derive_path`]: #method.derive_path
+    pub fn derive(&self, name: &[u8]) -> Uuid {
+        Uuid::new_v5(self, name)
+    }
+
+    /// Derives a descendant UUID from this UUID by deriving each segment of
+    /// `path` in turn.
+    ///
+    /// Each segment is derived with the result of the previous segment as
+    /// its parent, so `parent.derive_path(&["a", "b"])` is the same as
+    /// `parent.derive(b"a").derive(b"b")`. An empty path returns `self`.
+    ///
+    /// Note that usage of this method requires the `v5` feature of this crate
+    /// to be enabled.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use uuid::Uuid;
+    ///
+    /// let tenant = Uuid::new_v5(&Uuid::NAMESPACE_DNS, b"example.org");
+    /// let resource = tenant.derive_path(&["billing", "invoice-42"]);
+    ///
+    /// assert_eq!(resource, tenant.derive(b"billing").derive(b"invoice-42"));
+    /// ```
+    pub fn derive_path<S: AsRef<[u8]>>(&self, path: &[S]) -> Uuid {
+        path.iter()
+            .fold(*self, |parent, segment| parent.derive(segment.as_ref()))
+    }
 
-        builder.build()
+    /// Checks whether this UUID was derived from `parent` using `path`.
+    ///
+    /// This is equivalent to comparing against
+    /// `parent.derive_path(path)`.
+    ///
+    /// Note that usage of this method requires the `v5` feature of this crate
+    /// to be enabled.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use uuid::Uuid;
+    ///
+    /// let tenant = Uuid::new_v5(&Uuid::NAMESPACE_DNS, b"example.org");
+    /// let resource = tenant.derive_path(&["billing", "invoice-42"]);
+    ///
+    /// assert!(resource.is_derived_from(&tenant, &["billing", "invoice-42"]));
+    /// assert!(!resource.is_derived_from(&tenant, &["billing"]));
+    /// ```
+    pub fn is_derived_from<S: AsRef<[u8]>>(
+        &self,
+        parent: &Uuid,
+        path: &[S],
+    ) -> bool {
+        parent.derive_path(path) == *self
     }
 }
 
@@ -155,4 +250,49 @@ mod tests {
             assert_eq!(Ok(uuid), u.parse());
         }
     }
+
+    #[test]
+    fn test_derive() {
+        for &(ns, name, expected) in FIXTURE {
+            let uuid = ns.derive(name.as_bytes());
+
+            assert_eq!(uuid.to_hyphenated().to_string(), expected);
+        }
+    }
+
+    #[test]
+    fn test_derive_path() {
+        let parent = Uuid::NAMESPACE_DNS;
+        let child = parent.derive(b"example.org");
+        let grandchild = child.derive(b"rust-lang.org");
+
+        assert_eq!(parent.derive_path::<&str>(&[]), parent);
+        assert_eq!(parent.derive_path(&["example.org"]), child);
+        assert_eq!(
+            parent.derive_path(&["example.org", "rust-lang.org"]),
+            grandchild
+        );
+        assert_eq!(
+            parent.derive_path(&[&b"example.org"[..], &b"rust-lang.org"[..]]),
+            grandchild
+        );
+        assert_eq!(grandchild.get_version(), Some(Version::Sha1));
+    }
+
+    #[test]
+    fn test_is_derived_from() {
+        let parent = Uuid::NAMESPACE_DNS;
+        let grandchild = parent.derive_path(&["example.org", "rust-lang.org"]);
+
+        assert!(grandchild
+            .is_derived_from(&parent, &["example.org", "rust-lang.org"]));
+        assert!(grandchild.is_derived_from::<&str>(&grandchild, &[]));
+        assert!(!grandchild.is_derived_from(&parent, &["example.org"]));
+        assert!(!grandchild
+            .is_derived_from(&parent, &["rust-lang.org", "example.org"]));
+        assert!(!grandchild.is_derived_from(
+            &Uuid::NAMESPACE_URL,
+            &["example.org", "rust-lang.org"]
+        ));
+    }
 }
@@ -0,0 +1,95 @@
+use crate::prelude::*;
+
+impl Uuid {
+    /// Creates a time-ordered UUID from a Unix timestamp in milliseconds.
+    ///
+    /// This follows the UUIDv7 layout given in [RFC 9562 Section 5.7][RFC]:
+    /// the first 48 bits are the big-endian timestamp, and the rest, apart
+    /// from the version and variant bits, are random. UUIDs created in later
+    /// milliseconds sort after earlier ones, but UUIDs created within the
+    /// same millisecond aren't ordered relative to each other.
+    ///
+    /// Only the low 48 bits of `unix_millis` are used, which covers dates up
+    /// to the year 10889.
+    ///
+    /// This uses the [`getrandom`] crate to utilise the operating system's RNG
+    /// as the source of random numbers.
+    ///
+    /// Note that usage of this method requires the `v7` feature of this crate
+    /// to be enabled.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use uuid::{Uuid, Version};
+    ///
+    /// let uuid = Uuid::new_v7(1_645_557_742_000);
+    ///
+    /// assert_eq!(uuid.get_version(), Some(Version::SortRand));
+    ///
+    /// let text = uuid.to_hyphenated().to_string();
+    /// assert!(text.starts_with("017f22e2-79b0-7"));
+    /// ```
+    ///
+    /// [RFC]: https://www.rfc-editor.org/rfc/rfc9562#section-5.7
+    /// [`getrandom`]: https://crates.io/crates/getrandom
+    pub fn new_v7(unix_millis: u64) -> Uuid {
+        let mut random = [0u8; 10];
+        getrandom::getrandom(&mut random).unwrap_or_else(|err| {
+            // NB: getrandom::Error has no source; this is adequate display
+            panic!("could not retreive random bytes for uuid: {}", err)
+        });
+
+        from_unix_millis(unix_millis, random)
+    }
+}
+
+/// Builds a UUIDv7 from a timestamp and the bytes that follow it.
+fn from_unix_millis(unix_millis: u64, random: [u8; 10]) -> Uuid {
+    let mut bytes = [0u8; 16];
+    bytes[..6].copy_from_slice(&unix_millis.to_be_bytes()[2..]);
+    bytes[6..].copy_from_slice(&random);
+
+    crate::Builder::from_bytes(bytes)
+        .set_variant(Variant::RFC4122)
+        .set_version(Version::SortRand)
+        .build()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::std::string::ToString;
+
+    #[test]
+    fn test_from_unix_millis() {
+        let uuid = from_unix_millis(0x0123_4567_89ab, [0xff; 10]);
+
+        assert_eq!(
+            uuid.to_hyphenated().to_string(),
+            "01234567-89ab-7fff-bfff-ffffffffffff"
+        );
+        assert_eq!(uuid.get_version(), Some(Version::SortRand));
+        assert_eq!(uuid.get_variant(), Some(Variant::RFC4122));
+
+        assert_eq!(
+            from_unix_millis(u64::MAX, [0; 10])
+                .to_hyphenated()
+                .to_string(),
+            "ffffffff-ffff-7000-8000-000000000000"
+        );
+    }
+
+    #[test]
+    fn test_new() {
+        let a = Uuid::new_v7(1_645_557_742_000);
+        let b = Uuid::new_v7(1_645_557_742_001);
+
+        assert_eq!(a.get_version(), Some(Version::SortRand));
+        assert_eq!(a.get_variant(), Some(Variant::RFC4122));
+        assert_eq!(&a.as_bytes()[..6], &[0x01, 0x7f, 0x22, 0xe2, 0x79, 0xb0]);
+        assert!(a < b);
+        assert_ne!(a, Uuid::new_v7(1_645_557_742_000));
+    }
+}
@@ -0,0 +1,154 @@
+use crate::prelude::*;
+use sha2::{Sha256, Sha512};
+
+impl Uuid {
+    /// Creates a UUID using a name from a namespace, based on the SHA-256
+    /// hash.
+    ///
+    /// This follows the name-based UUIDv8 example given in
+    /// [RFC 9562 Appendix B.2][RFC]: the namespace and name are hashed
+    /// together, the hash is truncated to 128 bits, and the version and
+    /// variant bits are then set.
+    ///
+    /// A number of namespaces are available as constants in this crate:
+    ///
+    /// * [`NAMESPACE_DNS`]
+    /// * [`NAMESPACE_OID`]
+    /// * [`NAMESPACE_URL`]
+    /// * [`NAMESPACE_X500`]
+    ///
+    /// Note that usage of this method requires the `v8` feature of this crate
+    /// to be enabled.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use uuid::Uuid;
+    ///
+    /// let uuid =
+    ///     Uuid::new_v8_sha256(&Uuid::NAMESPACE_DNS, b"www.example.com");
+    ///
+    /// assert_eq!(
+    ///     uuid.to_hyphenated().to_string(),
+    ///     "5c146b14-3c52-8afd-938a-375d0df1fbf6"
+    /// );
+    /// ```
+    ///
+    /// [RFC]: https://www.rfc-editor.org/rfc/rfc9562#appendix-B.2
+    /// [`NAMESPACE_DNS`]: struct.Uuid.html#associatedconst.NAMESPACE_DNS
+    /// [`NAMESPACE_OID`]: struct.Uuid.html#associatedconst.NAMESPACE_OID
+    /// [`NAMESPACE_URL`]: struct.Uuid.html#associatedconst.NAMESPACE_URL
+    /// [`NAMESPACE_X500`]: struct.Uuid.html#associatedconst.NAMESPACE_X500
+    pub fn new_v8_sha256(namespace: &Uuid, name: &[u8]) -> Uuid {
+        Uuid::new_name_based::<Sha256>(Version::Custom, namespace, name)
+    }
+
+    /// Creates a UUID using a name from a namespace, based on the SHA-512
+    /// hash.
+    ///
+    /// This works the same way as [`new_v8_sha256`], but truncates a
+    /// SHA-512 hash instead.
+    ///
+    /// Note that usage of this method requires the `v8` feature of this crate
+    /// to be enabled.
+    ///
+    /// [`new_v8_sha256`]: #method.new_v8_sha256
+    pub fn new_v8_sha512(namespace: &Uuid, name: &[u8]) -> Uuid {
+        Uuid::new_name_based::<Sha512>(Version::Custom, namespace, name)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::std::string::ToString;
+
+    static FIXTURE_SHA256: &[(&Uuid, &str, &str)] = &[
+        // RFC 9562 Appendix B.2
+        (
+            &Uuid::NAMESPACE_DNS,
+            "www.example.com",
+            "5c146b14-3c52-8afd-938a-375d0df1fbf6",
+        ),
+        (
+            &Uuid::NAMESPACE_DNS,
+            "rust-lang.org",
+            "4da01707-1365-8fb2-8751-c9b55bf38ba8",
+        ),
+        (
+            &Uuid::NAMESPACE_URL,
+            "example.org",
+            "7b623d06-b041-8394-88b4-0ffe9ed68ea3",
+        ),
+        (
+            &Uuid::NAMESPACE_OID,
+            "42",
+            "e478498f-8d46-8039-8b7c-913665e67abc",
+        ),
+        (
+            &Uuid::NAMESPACE_X500,
+            "lorem ipsum",
+            "7611908e-d49d-8dc2-9a3b-5676aa84a6a9",
+        ),
+    ];
+
+    static FIXTURE_SHA512: &[(&Uuid, &str, &str)] = &[
+        (
+            &Uuid::NAMESPACE_DNS,
+            "www.example.com",
+            "94ee4ddb-9f36-8018-9ccf-86a4441691e0",
+        ),
+        (
+            &Uuid::NAMESPACE_DNS,
+            "rust-lang.org",
+            "d7de3ad2-8d80-809a-ac0f-1dbcca66d0e7",
+        ),
+        (
+            &Uuid::NAMESPACE_URL,
+            "example.org",
+            "fb150272-7202-8959-9ac9-f38f40346caf",
+        ),
+        (
+            &Uuid::NAMESPACE_OID,
+            "42",
+            "6735b9b8-f2bb-860f-b9d5-315a1c4ccaeb",
+        ),
+        (
+            &Uuid::NAMESPACE_X500,
+            "lorem ipsum",
+            "2caf8141-690e-86f1-9b3a-ff93b5e0cd27",
+        ),
+    ];
+
+    #[test]
+    fn test_get_version() {
+        let uuid = Uuid::new_v8_sha256(
+            &Uuid::NAMESPACE_DNS,
+            "rust-lang.org".as_bytes(),
+        );
+
+        assert_eq!(uuid.get_version(), Some(Version::Custom));
+        assert_eq!(uuid.get_version_num(), 8);
+    }
+
+    #[test]
+    fn test_sha256_hyphenated() {
+        for &(ns, name, expected) in FIXTURE_SHA256 {
+            let uuid = Uuid::new_v8_sha256(ns, name.as_bytes());
+
+            assert_eq!(uuid.get_variant(), Some(Variant::RFC4122));
+            assert_eq!(uuid.to_hyphenated().to_string(), expected);
+        }
+    }
+
+    #[test]
+    fn test_sha512_hyphenated() {
+        for &(ns, name, expected) in FIXTURE_SHA512 {
+            let uuid = Uuid::new_v8_sha512(ns, name.as_bytes());
+
+            assert_eq!(uuid.get_variant(), Some(Variant::RFC4122));
+            assert_eq!(uuid.to_hyphenated().to_string(), expected);
+        }
+    }
+}